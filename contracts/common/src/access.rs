@@ -0,0 +1,93 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::events::{self, AdminChanged, OperatorChanged};
+use crate::storage;
+
+#[contracttype]
+#[derive(Clone)]
+enum AccessKey {
+    Admin,
+    Operator(Address),
+}
+
+// Failures shared by every contract's admin checks. Each contract maps
+// these onto its own error enum so codes stay stable per contract.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AccessError {
+    NotInitialized,
+    AlreadyInitialized,
+    Unauthorized,
+}
+
+pub fn has_admin(env: &Env) -> bool {
+    env.storage().instance().has(&AccessKey::Admin)
+}
+
+pub fn read_admin(env: &Env) -> Result<Address, AccessError> {
+    env.storage()
+        .instance()
+        .get(&AccessKey::Admin)
+        .ok_or(AccessError::NotInitialized)
+}
+
+pub fn write_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&AccessKey::Admin, admin);
+    events::publish(env, events::ADMIN, events::CHANGED, AdminChanged { admin: admin.clone() });
+}
+
+// Set the first admin, once
+pub fn initialize(env: &Env, admin: &Address) -> Result<(), AccessError> {
+    if has_admin(env) {
+        return Err(AccessError::AlreadyInitialized);
+    }
+    admin.require_auth();
+    write_admin(env, admin);
+    Ok(())
+}
+
+// Require the stored admin to have signed this invocation
+pub fn require_admin(env: &Env) -> Result<Address, AccessError> {
+    let admin = read_admin(env)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+// Require `caller` to have signed and be either the admin or an operator
+pub fn require_operator(env: &Env, caller: &Address) -> Result<(), AccessError> {
+    let admin = read_admin(env)?;
+    caller.require_auth();
+    if *caller != admin && !is_operator(env, caller) {
+        return Err(AccessError::Unauthorized);
+    }
+    Ok(())
+}
+
+pub fn transfer_admin(env: &Env, new_admin: &Address) -> Result<(), AccessError> {
+    require_admin(env)?;
+    new_admin.require_auth();
+    write_admin(env, new_admin);
+    Ok(())
+}
+
+pub fn is_operator(env: &Env, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&AccessKey::Operator(account.clone()))
+}
+
+pub fn set_operator(env: &Env, account: &Address, enabled: bool) -> Result<(), AccessError> {
+    require_admin(env)?;
+    let key = AccessKey::Operator(account.clone());
+    if enabled {
+        storage::write_persistent(env, &key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+    events::publish(
+        env,
+        events::OPERATOR,
+        events::CHANGED,
+        OperatorChanged { account: account.clone(), enabled },
+    );
+    Ok(())
+}
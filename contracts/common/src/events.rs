@@ -0,0 +1,109 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, IntoVal, Symbol, Val};
+
+// Every state change in every game contract publishes exactly one event:
+//
+//   topics: (namespace, action, EVENT_VERSION)
+//   data:   a #[contracttype] payload struct
+//
+// Payloads carry enough to apply the change without reading contract
+// storage, so an indexer can rebuild game state from events alone.
+// Changing any payload layout bumps EVENT_VERSION.
+pub const EVENT_VERSION: u32 = 1;
+
+// Namespaces
+pub const ADMIN: Symbol = symbol_short!("ADMIN");
+pub const OPERATOR: Symbol = symbol_short!("OPERATOR");
+pub const CONTRACT: Symbol = symbol_short!("CONTRACT");
+pub const REGISTRY: Symbol = symbol_short!("REGISTRY");
+pub const PLAYER: Symbol = symbol_short!("PLAYER");
+pub const SHIP: Symbol = symbol_short!("SHIP");
+pub const STAR: Symbol = symbol_short!("STAR");
+pub const RESOURCE: Symbol = symbol_short!("RESOURCE");
+pub const PRICE: Symbol = symbol_short!("PRICE");
+pub const OFFER: Symbol = symbol_short!("OFFER");
+pub const MISSION: Symbol = symbol_short!("MISSION");
+pub const ACHIEVE: Symbol = symbol_short!("ACHIEVE");
+pub const RECIPE: Symbol = symbol_short!("RECIPE");
+pub const CRAFT: Symbol = symbol_short!("CRAFT");
+pub const BUILD: Symbol = symbol_short!("BUILD");
+pub const MODULE: Symbol = symbol_short!("MODULE");
+pub const CARGO: Symbol = symbol_short!("CARGO");
+pub const GALAXY: Symbol = symbol_short!("GALAXY");
+pub const DEPOSIT: Symbol = symbol_short!("DEPOSIT");
+
+// Actions
+pub const CHANGED: Symbol = symbol_short!("CHANGED");
+pub const UPGRADED: Symbol = symbol_short!("UPGRADED");
+pub const VERSION: Symbol = symbol_short!("VERSION");
+pub const LINKED: Symbol = symbol_short!("LINKED");
+pub const SET: Symbol = symbol_short!("SET");
+pub const REMOVED: Symbol = symbol_short!("REMOVED");
+pub const INSTALLED: Symbol = symbol_short!("INSTALLED");
+pub const LOADED: Symbol = symbol_short!("LOADED");
+pub const UNLOADED: Symbol = symbol_short!("UNLOADED");
+pub const JOINED: Symbol = symbol_short!("JOINED");
+pub const CREATED: Symbol = symbol_short!("CREATED");
+pub const REPAIRED: Symbol = symbol_short!("REPAIRED");
+pub const COLLECTED: Symbol = symbol_short!("COLLECTED");
+pub const CREDITED: Symbol = symbol_short!("CREDITED");
+pub const DEBITED: Symbol = symbol_short!("DEBITED");
+pub const DISCOVERY: Symbol = symbol_short!("DISCOVERY");
+pub const EXTRACTED: Symbol = symbol_short!("EXTRACTED");
+pub const DEPLETED: Symbol = symbol_short!("DEPLETED");
+pub const REPLENISH: Symbol = symbol_short!("REPLENISH");
+pub const TRANSFER: Symbol = symbol_short!("TRANSFER");
+pub const BOUGHT: Symbol = symbol_short!("BOUGHT");
+pub const SOLD: Symbol = symbol_short!("SOLD");
+pub const ACCEPTED: Symbol = symbol_short!("ACCEPTED");
+pub const CANCELLED: Symbol = symbol_short!("CANCELLED");
+pub const COMPLETED: Symbol = symbol_short!("COMPLETED");
+pub const REFUELED: Symbol = symbol_short!("REFUELED");
+pub const DEPARTED: Symbol = symbol_short!("DEPARTED");
+pub const ARRIVED: Symbol = symbol_short!("ARRIVED");
+pub const SKIPPED: Symbol = symbol_short!("SKIPPED");
+
+pub fn publish<D>(env: &Env, namespace: Symbol, action: Symbol, payload: D)
+where
+    D: IntoVal<Env, Val>,
+{
+    env.events()
+        .publish((namespace, action, EVENT_VERSION), payload);
+}
+
+// Payloads published by the shared admin, upgrade and registry helpers
+
+// (ADMIN, CHANGED): the contract has a new admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChanged {
+    pub admin: Address,
+}
+
+// (OPERATOR, CHANGED): an operator was granted or revoked
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperatorChanged {
+    pub account: Address,
+    pub enabled: bool,
+}
+
+// (CONTRACT, UPGRADED): new code was installed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upgraded {
+    pub wasm_hash: BytesN<32>,
+}
+
+// (CONTRACT, VERSION): the stored schema version was set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionSet {
+    pub version: u32,
+}
+
+// (REGISTRY, LINKED): the contract now resolves others through `registry`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryLinked {
+    pub registry: Address,
+}
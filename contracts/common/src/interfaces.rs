@@ -0,0 +1,72 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Map, Symbol, Vec};
+
+use crate::registry::Role;
+use crate::resources::ResourceInfo;
+
+// Entry points game contracts call on each other. Each trait generates a
+// typed client; the implementations live in the contracts' own crates.
+
+#[contractclient(name = "RegistryClient")]
+pub trait RegistryInterface {
+    fn get_contract(env: Env, role: Role) -> Address;
+    fn get_resource(env: Env, resource: Symbol) -> ResourceInfo;
+}
+
+// The two legs of an accepted trade offer: `sell_amount` of
+// `sell_resource` goes from seller to buyer, `buy_amount` of `buy_resource`
+// comes back
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trade {
+    pub seller: Address,
+    pub buyer: Address,
+    pub sell_resource: Symbol,
+    pub sell_amount: u64,
+    pub buy_resource: Symbol,
+    pub buy_amount: u64,
+}
+
+#[contractclient(name = "EconomyClient")]
+pub trait EconomyInterface {
+    fn get_resource_price(env: Env, resource: Symbol) -> u64;
+    fn get_player_resources(env: Env, player: Address) -> Map<Symbol, u64>;
+    fn get_balance(env: Env, player: Address, resource: Symbol) -> u64;
+    fn credit(env: Env, caller: Address, player: Address, resource: Symbol, amount: u64);
+    fn debit(env: Env, caller: Address, player: Address, resource: Symbol, amount: u64);
+    fn transfer_resources(env: Env, from: Address, to: Address, resource: Symbol, amount: u64);
+    fn settle_trade(env: Env, caller: Address, trade: Trade);
+}
+
+#[contractclient(name = "StarSystemClient")]
+pub trait StarSystemInterface {
+    fn get_resource_amount(env: Env, star_name: Symbol, resource_name: Symbol) -> i128;
+    fn extract(env: Env, caller: Address, star_name: Symbol, resource_name: Symbol, amount: i128) -> i128;
+    fn get_all_stars(env: Env) -> Vec<Symbol>;
+    fn get_distance(env: Env, from: Symbol, to: Symbol) -> u64;
+    fn get_discovery_count(env: Env, player: Address) -> u32;
+    fn has_discovered(env: Env, player: Address, star_name: Symbol) -> bool;
+}
+
+// What a ship's sensors can reach: the star it is docked at, or None while
+// it is travelling, and how far it scans from there
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Scanner {
+    pub owner: Address,
+    pub star: Option<Symbol>,
+    pub scan_range: u64,
+}
+
+#[contractclient(name = "ShipyardClient")]
+pub trait ShipyardInterface {
+    fn order_ship(env: Env, owner: Address, name: Symbol, type_: Symbol) -> u64;
+    fn get_scanner(env: Env, ship_id: u64) -> Scanner;
+}
+
+// Supply entry points of a resource token; holders use soroban_sdk::token
+#[contractclient(name = "ResourceTokenClient")]
+pub trait ResourceTokenInterface {
+    fn mint(env: Env, minter: Address, to: Address, amount: i128);
+    fn consume(env: Env, minter: Address, from: Address, amount: i128);
+    fn balance(env: Env, id: Address) -> i128;
+}
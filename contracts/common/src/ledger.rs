@@ -0,0 +1,130 @@
+use soroban_sdk::{contracttype, Address, Env, Map, Symbol};
+
+use crate::storage;
+
+// The game's resource balances. Only the Economy contract keeps a ledger;
+// every other contract reads and writes balances through its entry points.
+//
+// `PlayerResources` encodes the same as the Economy's original key, so
+// balances written before the ledger existed are read in place.
+#[contracttype]
+#[derive(Clone)]
+enum LedgerKey {
+    PlayerResources(Address),
+    Supply(Symbol),
+}
+
+// Failures moving balances. Each contract maps these onto its own error
+// enum, as with AccessError.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LedgerError {
+    InvalidAmount,
+    InsufficientBalance,
+    Overflow,
+}
+
+impl LedgerError {
+    // Contract error code the Economy reports this failure under, so callers
+    // of its `try_` client methods can tell the failures apart
+    pub const fn code(self) -> u32 {
+        match self {
+            LedgerError::InsufficientBalance => 2,
+            LedgerError::InvalidAmount => 3,
+            LedgerError::Overflow => 110,
+        }
+    }
+}
+
+pub fn balances(env: &Env, account: &Address) -> Map<Symbol, u64> {
+    storage::read_persistent(env, &LedgerKey::PlayerResources(account.clone()))
+        .unwrap_or_else(|| Map::new(env))
+}
+
+pub fn balance(env: &Env, account: &Address, resource: &Symbol) -> u64 {
+    balances(env, account).get(resource.clone()).unwrap_or(0)
+}
+
+// Units of `resource` held across all accounts
+pub fn supply(env: &Env, resource: &Symbol) -> u64 {
+    env.storage()
+        .instance()
+        .get(&LedgerKey::Supply(resource.clone()))
+        .unwrap_or(0)
+}
+
+// Create `amount` of `resource` in `account`
+pub fn credit(env: &Env, account: &Address, resource: &Symbol, amount: u64) -> Result<(), LedgerError> {
+    check_amount(amount)?;
+    let supply = supply(env, resource)
+        .checked_add(amount)
+        .ok_or(LedgerError::Overflow)?;
+    add(env, account, resource, amount)?;
+    write_supply(env, resource, supply);
+    Ok(())
+}
+
+// Destroy `amount` of `resource` held by `account`
+pub fn debit(env: &Env, account: &Address, resource: &Symbol, amount: u64) -> Result<(), LedgerError> {
+    check_amount(amount)?;
+    subtract(env, account, resource, amount)?;
+    // Balances written before supply was tracked are not counted in it
+    write_supply(env, resource, supply(env, resource).saturating_sub(amount));
+    Ok(())
+}
+
+// Move `amount` of `resource` between accounts; supply is unchanged
+pub fn transfer(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    resource: &Symbol,
+    amount: u64,
+) -> Result<(), LedgerError> {
+    check_amount(amount)?;
+    subtract(env, from, resource, amount)?;
+    add(env, to, resource, amount)
+}
+
+fn check_amount(amount: u64) -> Result<(), LedgerError> {
+    if amount == 0 {
+        return Err(LedgerError::InvalidAmount);
+    }
+    Ok(())
+}
+
+fn add(env: &Env, account: &Address, resource: &Symbol, amount: u64) -> Result<(), LedgerError> {
+    let mut held = balances(env, account);
+    let balance = held
+        .get(resource.clone())
+        .unwrap_or(0)
+        .checked_add(amount)
+        .ok_or(LedgerError::Overflow)?;
+    held.set(resource.clone(), balance);
+    write_balances(env, account, &held);
+    Ok(())
+}
+
+fn subtract(env: &Env, account: &Address, resource: &Symbol, amount: u64) -> Result<(), LedgerError> {
+    let mut held = balances(env, account);
+    let balance = held.get(resource.clone()).unwrap_or(0);
+    if balance < amount {
+        return Err(LedgerError::InsufficientBalance);
+    }
+    if balance == amount {
+        held.remove(resource.clone());
+    } else {
+        held.set(resource.clone(), balance - amount);
+    }
+    write_balances(env, account, &held);
+    Ok(())
+}
+
+fn write_balances(env: &Env, account: &Address, held: &Map<Symbol, u64>) {
+    storage::write_persistent(env, &LedgerKey::PlayerResources(account.clone()), held);
+}
+
+fn write_supply(env: &Env, resource: &Symbol, supply: u64) {
+    env.storage()
+        .instance()
+        .set(&LedgerKey::Supply(resource.clone()), &supply);
+}
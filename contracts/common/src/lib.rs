@@ -0,0 +1,16 @@
+#![no_std]
+
+// Types and constants shared by every game contract.
+
+pub mod access;
+pub mod events;
+pub mod interfaces;
+pub mod ledger;
+pub mod math;
+pub mod registry;
+pub mod resources;
+pub mod storage;
+pub mod upgrade;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,246 @@
+// Deterministic fixed-point arithmetic. Values are i128 scaled by SCALE
+// (7 decimal places, the precision of Stellar assets), so every host
+// computes bit-identical results without touching floats.
+
+pub const SCALE: i128 = 10_000_000;
+pub const PI: i128 = 31_415_927;
+pub const TWO_PI: i128 = 62_831_853;
+
+// The series below run at 18 decimal places and round back to SCALE, so
+// truncation in intermediate terms does not show in the result
+const WIDE: i128 = 1_000_000_000_000_000_000;
+const WIDEN_BY: i128 = WIDE / SCALE;
+const WIDE_PI: i128 = 3_141_592_653_589_793_238;
+const WIDE_LN_2: i128 = 693_147_180_559_945_309;
+const SERIES_TERMS: i128 = 20;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MathError {
+    Overflow,
+    DivisionByZero,
+    OutOfDomain,
+}
+
+pub fn from_int(value: i128) -> Result<i128, MathError> {
+    value.checked_mul(SCALE).ok_or(MathError::Overflow)
+}
+
+// Whole part, truncated toward zero
+pub fn to_int(value: i128) -> i128 {
+    value / SCALE
+}
+
+pub fn from_ratio(numerator: i128, denominator: i128) -> Result<i128, MathError> {
+    div(from_int(numerator)?, from_int(denominator)?)
+}
+
+pub fn add(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_add(b).ok_or(MathError::Overflow)
+}
+
+pub fn sub(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_sub(b).ok_or(MathError::Overflow)
+}
+
+pub fn mul(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_mul(b)
+        .map(|product| product / SCALE)
+        .ok_or(MathError::Overflow)
+}
+
+pub fn div(a: i128, b: i128) -> Result<i128, MathError> {
+    if b == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+    a.checked_mul(SCALE)
+        .map(|scaled| scaled / b)
+        .ok_or(MathError::Overflow)
+}
+
+// Square root, rounded down
+pub fn sqrt(value: i128) -> Result<i128, MathError> {
+    if value < 0 {
+        return Err(MathError::OutOfDomain);
+    }
+    Ok(isqrt(value.checked_mul(SCALE).ok_or(MathError::Overflow)?))
+}
+
+// Integer square root, rounded down
+pub fn isqrt(value: i128) -> i128 {
+    if value < 2 {
+        return value.max(0);
+    }
+    // Newton's method from an initial guess above the root
+    let mut x = value;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+fn widen(value: i128) -> Result<i128, MathError> {
+    value.checked_mul(WIDEN_BY).ok_or(MathError::Overflow)
+}
+
+// Back to SCALE, rounding half away from zero
+fn narrow(value: i128) -> i128 {
+    let half = WIDEN_BY / 2;
+    if value < 0 {
+        (value - half) / WIDEN_BY
+    } else {
+        (value + half) / WIDEN_BY
+    }
+}
+
+fn wide_mul(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_mul(b)
+        .map(|product| product / WIDE)
+        .ok_or(MathError::Overflow)
+}
+
+// Sine of an angle in radians
+pub fn sin(angle: i128) -> Result<i128, MathError> {
+    // Reduce to [-PI, PI], then fold onto [-PI/2, PI/2] where the series
+    // converges quickly
+    let mut x = widen(angle % TWO_PI)?;
+    if x > WIDE_PI {
+        x -= 2 * WIDE_PI;
+    } else if x < -WIDE_PI {
+        x += 2 * WIDE_PI;
+    }
+    if x > WIDE_PI / 2 {
+        x = WIDE_PI - x;
+    } else if x < -WIDE_PI / 2 {
+        x = -WIDE_PI - x;
+    }
+
+    // x - x^3/3! + x^5/5! - ...
+    let x_squared = wide_mul(x, x)?;
+    let mut term = x;
+    let mut sum = x;
+    for n in 1..SERIES_TERMS {
+        term = -wide_mul(term, x_squared)? / ((2 * n) * (2 * n + 1));
+        if term == 0 {
+            break;
+        }
+        sum += term;
+    }
+    Ok(narrow(sum))
+}
+
+// Natural logarithm
+pub fn ln(value: i128) -> Result<i128, MathError> {
+    Ok(narrow(wide_ln(value)?))
+}
+
+// e raised to `value`
+pub fn exp(value: i128) -> Result<i128, MathError> {
+    wide_exp(widen(value)?)
+}
+
+// ln of a SCALE value, at the wide scale
+fn wide_ln(value: i128) -> Result<i128, MathError> {
+    if value <= 0 {
+        return Err(MathError::OutOfDomain);
+    }
+
+    // Write value = m * 2^k with m in [1, 2)
+    let mut m = widen(value)?;
+    let mut k: i128 = 0;
+    while m >= 2 * WIDE {
+        m /= 2;
+        k += 1;
+    }
+    while m < WIDE {
+        m *= 2;
+        k -= 1;
+    }
+
+    // ln(m) = 2 * (y + y^3/3 + y^5/5 + ...) with y = (m - 1) / (m + 1)
+    let y = (m - WIDE) * WIDE / (m + WIDE);
+    let y_squared = wide_mul(y, y)?;
+    let mut power = y;
+    let mut sum = y;
+    for n in 1..SERIES_TERMS {
+        power = wide_mul(power, y_squared)?;
+        if power == 0 {
+            break;
+        }
+        sum += power / (2 * n + 1);
+    }
+
+    Ok(k * WIDE_LN_2 + 2 * sum)
+}
+
+// exp of a wide-scale value, back at SCALE
+fn wide_exp(x: i128) -> Result<i128, MathError> {
+    // Write x = k * ln(2) + r with r in [0, ln(2))
+    let k = x.div_euclid(WIDE_LN_2);
+    let r = x.rem_euclid(WIDE_LN_2);
+
+    // 1 + r + r^2/2! + r^3/3! + ...
+    let mut term = WIDE;
+    let mut sum = WIDE;
+    for n in 1..SERIES_TERMS {
+        term = wide_mul(term, r)? / n;
+        if term == 0 {
+            break;
+        }
+        sum += term;
+    }
+
+    let result = narrow(sum);
+    if k >= 0 {
+        let shift = u32::try_from(k).map_err(|_| MathError::Overflow)?;
+        result
+            .checked_mul(2i128.checked_pow(shift).ok_or(MathError::Overflow)?)
+            .ok_or(MathError::Overflow)
+    } else {
+        // Underflows to zero for large negative exponents
+        let shift = u32::try_from(-k).unwrap_or(u32::MAX);
+        Ok(2i128.checked_pow(shift).map_or(0, |divisor| result / divisor))
+    }
+}
+
+// `base` raised to a whole power, by repeated squaring
+pub fn powi(base: i128, exponent: u32) -> Result<i128, MathError> {
+    let mut result = SCALE;
+    let mut square = base;
+    let mut remaining = exponent;
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result = mul(result, square)?;
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            square = mul(square, square)?;
+        }
+    }
+    Ok(result)
+}
+
+// `base` raised to a fixed-point power. Whole exponents are computed
+// exactly; the fractional part goes through exp and ln.
+pub fn pow(base: i128, exponent: i128) -> Result<i128, MathError> {
+    if exponent < 0 {
+        return div(SCALE, pow(base, -exponent)?);
+    }
+
+    let whole = u32::try_from(exponent / SCALE).map_err(|_| MathError::Overflow)?;
+    let fraction = exponent % SCALE;
+    let whole_part = powi(base, whole)?;
+    if fraction == 0 {
+        return Ok(whole_part);
+    }
+    if base == 0 {
+        return Ok(0);
+    }
+    if base < 0 {
+        return Err(MathError::OutOfDomain);
+    }
+
+    let fraction_part = wide_exp(wide_mul(widen(fraction)?, wide_ln(base)?)?)?;
+    mul(whole_part, fraction_part)
+}
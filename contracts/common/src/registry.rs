@@ -0,0 +1,104 @@
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::access::{self, AccessError};
+use crate::events::{self, RegistryLinked};
+use crate::interfaces::{
+    EconomyClient, RegistryClient, ResourceTokenClient, ShipyardClient, StarSystemClient,
+};
+use crate::resources::ResourceInfo;
+
+// Parts of the game a contract can be registered as
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Role {
+    SpaceGame,
+    StarSystem,
+    Missions,
+    Trading,
+    Economy,
+    Shipyard,
+    Mission,
+    // The SEP-41 token issued for a resource symbol
+    ResourceToken(Symbol),
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum RegistryKey {
+    Registry,
+}
+
+// Failures looking up another contract. Each contract maps these onto its
+// own error enum, as with AccessError.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RegistryError {
+    RegistryNotSet,
+    NotRegistered,
+    UnknownResource,
+    NotTradable,
+}
+
+// Point this contract at the registry it resolves other contracts through.
+// Admin only.
+pub fn set_registry(env: &Env, registry: &Address) -> Result<(), AccessError> {
+    access::require_admin(env)?;
+    env.storage().instance().set(&RegistryKey::Registry, registry);
+    events::publish(
+        env,
+        events::REGISTRY,
+        events::LINKED,
+        RegistryLinked { registry: registry.clone() },
+    );
+    Ok(())
+}
+
+pub fn read_registry(env: &Env) -> Result<Address, RegistryError> {
+    env.storage()
+        .instance()
+        .get(&RegistryKey::Registry)
+        .ok_or(RegistryError::RegistryNotSet)
+}
+
+// Address of the contract registered for `role`
+pub fn lookup(env: &Env, role: Role) -> Result<Address, RegistryError> {
+    let registry = RegistryClient::new(env, &read_registry(env)?);
+    match registry.try_get_contract(&role) {
+        Ok(Ok(address)) => Ok(address),
+        _ => Err(RegistryError::NotRegistered),
+    }
+}
+
+// Metadata for `resource`, failing unless the registry knows it
+pub fn resource(env: &Env, resource: &Symbol) -> Result<ResourceInfo, RegistryError> {
+    let registry = RegistryClient::new(env, &read_registry(env)?);
+    match registry.try_get_resource(resource) {
+        Ok(Ok(info)) => Ok(info),
+        _ => Err(RegistryError::UnknownResource),
+    }
+}
+
+// As `resource`, also failing for resources that cannot be traded
+pub fn tradable_resource(env: &Env, resource: &Symbol) -> Result<ResourceInfo, RegistryError> {
+    let info = self::resource(env, resource)?;
+    if !info.tradable {
+        return Err(RegistryError::NotTradable);
+    }
+    Ok(info)
+}
+
+pub fn economy(env: &Env) -> Result<EconomyClient<'_>, RegistryError> {
+    Ok(EconomyClient::new(env, &lookup(env, Role::Economy)?))
+}
+
+pub fn star_system(env: &Env) -> Result<StarSystemClient<'_>, RegistryError> {
+    Ok(StarSystemClient::new(env, &lookup(env, Role::StarSystem)?))
+}
+
+pub fn shipyard(env: &Env) -> Result<ShipyardClient<'_>, RegistryError> {
+    Ok(ShipyardClient::new(env, &lookup(env, Role::Shipyard)?))
+}
+
+pub fn resource_token<'a>(env: &'a Env, resource: &Symbol) -> Result<ResourceTokenClient<'a>, RegistryError> {
+    let address = lookup(env, Role::ResourceToken(resource.clone()))?;
+    Ok(ResourceTokenClient::new(env, &address))
+}
@@ -0,0 +1,45 @@
+use soroban_sdk::{contracttype, symbol_short, Env, String, Symbol, Vec};
+
+// Base resources that exist in every galaxy
+pub const IRON: Symbol = symbol_short!("IRON");
+pub const WATER: Symbol = symbol_short!("WATER");
+pub const ENERGY: Symbol = symbol_short!("ENERGY");
+pub const GOLD: Symbol = symbol_short!("GOLD");
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Exotic,
+}
+
+// What the registry records about a resource
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceInfo {
+    pub name: String,
+    pub rarity: Rarity,
+    pub unit_mass: u64, // cargo mass of one unit
+    pub tradable: bool,
+}
+
+// Metadata the registry starts with for the base resources
+pub fn base_resources(env: &Env) -> Vec<(Symbol, ResourceInfo)> {
+    let info = |name, rarity, unit_mass| ResourceInfo {
+        name: String::from_str(env, name),
+        rarity,
+        unit_mass,
+        tradable: true,
+    };
+    Vec::from_array(
+        env,
+        [
+            (IRON, info("Iron", Rarity::Common, 2)),
+            (WATER, info("Water", Rarity::Common, 1)),
+            (ENERGY, info("Energy", Rarity::Uncommon, 1)),
+            (GOLD, info("Gold", Rarity::Rare, 3)),
+        ],
+    )
+}
@@ -0,0 +1,91 @@
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val};
+
+// Ledgers close roughly every five seconds
+pub const LEDGER_CLOSE_TIME: u64 = 5;
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+// Contract config lives in instance storage and shares the contract's TTL
+pub const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// Player and world records are pushed out to this TTL whenever touched
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 90 * DAY_IN_LEDGERS;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+pub fn extend_persistent<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Read a durable record, extending its TTL if it exists
+pub fn read_persistent<K, V>(env: &Env, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+{
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        extend_persistent(env, key);
+    }
+    value
+}
+
+// Write a durable record and extend its TTL
+pub fn write_persistent<K, V>(env: &Env, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().persistent().set(key, value);
+    extend_persistent(env, key);
+}
+
+// Write a short-lived entry, such as a cooldown, that expires on its own
+// after `ledgers` ledgers
+pub fn write_temporary<K, V>(env: &Env, key: &K, value: &V, ledgers: u32)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().temporary().set(key, value);
+    env.storage().temporary().extend_ttl(key, ledgers, ledgers);
+}
+
+// Move a value that older versions kept in persistent storage into
+// instance storage. Does nothing once the value has moved, and keeps any
+// instance value written since the upgrade, which is the newer one.
+pub fn move_to_instance<K, V>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    if let Some(value) = env.storage().persistent().get::<K, V>(key) {
+        if !env.storage().instance().has(key) {
+            env.storage().instance().set(key, &value);
+        }
+        env.storage().persistent().remove(key);
+    }
+}
+
+// Read a value that `move_to_instance` moves, falling back to the
+// persistent copy older versions wrote until the migration has run
+pub fn read_moved<K, V>(env: &Env, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+{
+    env.storage()
+        .instance()
+        .get(key)
+        .or_else(|| env.storage().persistent().get(key))
+}
@@ -0,0 +1,164 @@
+#![cfg(test)]
+
+use crate::ledger::{self, LedgerError};
+use crate::math::{self, MathError, PI, SCALE};
+use crate::storage;
+use soroban_sdk::{contract, symbol_short, testutils::Address as _, Address, Env};
+
+#[contract]
+struct Host;
+
+#[test]
+fn test_checked_arithmetic() {
+    assert_eq!(math::from_int(3), Ok(30_000_000));
+    assert_eq!(math::to_int(29_999_999), 2);
+    assert_eq!(math::from_ratio(1, 3), Ok(3_333_333));
+    assert_eq!(math::mul(15_000_000, 15_000_000), Ok(22_500_000));
+    assert_eq!(math::div(SCALE, 4 * SCALE), Ok(2_500_000));
+
+    assert_eq!(math::from_int(i128::MAX), Err(MathError::Overflow));
+    assert_eq!(math::add(i128::MAX, 1), Err(MathError::Overflow));
+    assert_eq!(math::sub(i128::MIN, 1), Err(MathError::Overflow));
+    assert_eq!(math::mul(i128::MAX, 2), Err(MathError::Overflow));
+    assert_eq!(math::div(SCALE, 0), Err(MathError::DivisionByZero));
+}
+
+#[test]
+fn test_sqrt() {
+    assert_eq!(math::sqrt(0), Ok(0));
+    assert_eq!(math::sqrt(2 * SCALE), Ok(14_142_135));
+    assert_eq!(math::sqrt(16 * SCALE), Ok(40_000_000));
+    assert_eq!(math::sqrt(-1), Err(MathError::OutOfDomain));
+    assert_eq!(math::isqrt(99), 9);
+}
+
+#[test]
+fn test_sin() {
+    assert_eq!(math::sin(0), Ok(0));
+    assert_eq!(math::sin(SCALE), Ok(8_414_710));
+    assert_eq!(math::sin(-SCALE), Ok(-8_414_710));
+    assert_eq!(math::sin(15_000_000), Ok(9_974_950));
+    assert_eq!(math::sin(PI / 2), Ok(SCALE));
+    assert_eq!(math::sin(PI), Ok(0));
+    assert_eq!(math::sin(10 * SCALE), Ok(-5_440_211));
+}
+
+#[test]
+fn test_ln_and_exp() {
+    assert_eq!(math::ln(SCALE), Ok(0));
+    assert_eq!(math::ln(2 * SCALE), Ok(6_931_472));
+    assert_eq!(math::ln(SCALE / 2), Ok(-6_931_472));
+    assert_eq!(math::ln(10 * SCALE), Ok(23_025_851));
+    assert_eq!(math::ln(0), Err(MathError::OutOfDomain));
+
+    assert_eq!(math::exp(0), Ok(SCALE));
+    assert_eq!(math::exp(SCALE), Ok(27_182_818));
+    assert_eq!(math::exp(-SCALE), Ok(3_678_794));
+    assert_eq!(math::exp(10 * SCALE), Ok(220_264_660_992));
+    assert_eq!(math::exp(-1_000 * SCALE), Ok(0));
+    assert_eq!(math::exp(1_000 * SCALE), Err(MathError::Overflow));
+}
+
+#[test]
+fn test_pow() {
+    let one_and_a_half = 15_000_000;
+
+    assert_eq!(math::pow(SCALE, one_and_a_half), Ok(SCALE));
+    assert_eq!(math::pow(2 * SCALE, one_and_a_half), Ok(28_284_272));
+    assert_eq!(math::pow(3 * SCALE, one_and_a_half), Ok(51_961_524));
+    assert_eq!(math::pow(4 * SCALE, one_and_a_half), Ok(80_000_000));
+    assert_eq!(math::pow(9 * SCALE, one_and_a_half), Ok(270_000_000));
+    assert_eq!(math::pow(10 * SCALE, one_and_a_half), Ok(316_227_760));
+    assert_eq!(math::pow(0, one_and_a_half), Ok(0));
+
+    assert_eq!(math::pow(2 * SCALE, 10 * SCALE), Ok(10_240_000_000));
+    assert_eq!(math::pow(2 * SCALE, -SCALE), Ok(5_000_000));
+    assert_eq!(math::pow(-2 * SCALE, one_and_a_half), Err(MathError::OutOfDomain));
+    assert_eq!(math::powi(10 * SCALE, 40), Err(MathError::Overflow));
+}
+
+#[test]
+fn test_ledger_conserves_supply() {
+    let env = Env::default();
+    let host = env.register_contract(None, Host);
+    let iron = symbol_short!("IRON");
+    let players = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+
+    env.as_contract(&host, || {
+        let held = |players: &[Address]| -> u64 {
+            players.iter().map(|player| ledger::balance(&env, player, &iron)).sum()
+        };
+
+        ledger::credit(&env, &players[0], &iron, 700).unwrap();
+        ledger::credit(&env, &players[1], &iron, 300).unwrap();
+        assert_eq!(ledger::supply(&env, &iron), 1_000);
+
+        // Transfers, including failed ones, never change the total
+        let moves = [(0, 1, 250), (1, 2, 400), (2, 0, 401), (2, 2, 100), (0, 2, 450)];
+        for (from, to, amount) in moves {
+            let _ = ledger::transfer(&env, &players[from], &players[to], &iron, amount);
+            assert_eq!(held(&players), 1_000);
+            assert_eq!(ledger::supply(&env, &iron), 1_000);
+        }
+        assert_eq!(ledger::balance(&env, &players[0], &iron), 0);
+        assert_eq!(ledger::balance(&env, &players[1], &iron), 150);
+        assert_eq!(ledger::balance(&env, &players[2], &iron), 850);
+
+        ledger::debit(&env, &players[2], &iron, 50).unwrap();
+        assert_eq!(held(&players), 950);
+        assert_eq!(ledger::supply(&env, &iron), 950);
+        assert_eq!(ledger::supply(&env, &symbol_short!("GOLD")), 0);
+    });
+}
+
+#[test]
+fn test_ledger_rejects_bad_moves() {
+    let env = Env::default();
+    let host = env.register_contract(None, Host);
+    let iron = symbol_short!("IRON");
+    let player = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    env.as_contract(&host, || {
+        assert_eq!(ledger::credit(&env, &player, &iron, 0), Err(LedgerError::InvalidAmount));
+        assert_eq!(
+            ledger::transfer(&env, &player, &other, &iron, 1),
+            Err(LedgerError::InsufficientBalance)
+        );
+        assert_eq!(ledger::debit(&env, &player, &iron, 1), Err(LedgerError::InsufficientBalance));
+
+        ledger::credit(&env, &player, &iron, u64::MAX).unwrap();
+        assert_eq!(ledger::credit(&env, &other, &iron, 1), Err(LedgerError::Overflow));
+        assert_eq!(ledger::balance(&env, &other, &iron), 0);
+        assert_eq!(ledger::supply(&env, &iron), u64::MAX);
+
+        ledger::transfer(&env, &player, &other, &iron, 5).unwrap();
+        assert!(!ledger::balances(&env, &other).is_empty());
+        ledger::transfer(&env, &other, &player, &iron, 5).unwrap();
+        assert!(ledger::balances(&env, &other).is_empty());
+    });
+}
+
+#[test]
+fn test_moved_values_read_before_migration() {
+    let env = Env::default();
+    let host = env.register_contract(None, Host);
+    let key = symbol_short!("COUNTER");
+
+    env.as_contract(&host, || {
+        assert_eq!(storage::read_moved::<_, u64>(&env, &key), None);
+
+        // Written by an older version
+        env.storage().persistent().set(&key, &3u64);
+        assert_eq!(storage::read_moved::<_, u64>(&env, &key), Some(3));
+
+        // Bumped after the upgrade but before the migration
+        env.storage().instance().set(&key, &4u64);
+        assert_eq!(storage::read_moved::<_, u64>(&env, &key), Some(4));
+
+        // Migrating keeps the newer value
+        storage::move_to_instance::<_, u64>(&env, &key);
+        assert_eq!(storage::read_moved::<_, u64>(&env, &key), Some(4));
+        assert!(!env.storage().persistent().has(&key));
+    });
+}
@@ -0,0 +1,44 @@
+use soroban_sdk::{contracttype, BytesN, Env, Map, Symbol, TryFromVal, Val};
+
+use crate::access::{self, AccessError};
+use crate::events::{self, Upgraded, VersionSet};
+
+#[contracttype]
+#[derive(Clone)]
+enum UpgradeKey {
+    SchemaVersion,
+}
+
+// Contracts deployed before schema versioning was introduced store no
+// version at all; their records use the version 1 layouts.
+pub const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+pub fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&UpgradeKey::SchemaVersion)
+        .unwrap_or(LEGACY_SCHEMA_VERSION)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&UpgradeKey::SchemaVersion, &version);
+    events::publish(env, events::CONTRACT, events::VERSION, VersionSet { version });
+}
+
+// Replace the contract code, keeping its storage. Admin only.
+pub fn upgrade(env: &Env, wasm_hash: BytesN<32>) -> Result<(), AccessError> {
+    access::require_admin(env)?;
+    env.deployer().update_current_contract_wasm(wasm_hash.clone());
+    events::publish(env, events::CONTRACT, events::UPGRADED, Upgraded { wasm_hash });
+    Ok(())
+}
+
+// Whether a stored struct record carries `field`. Migrations use this to
+// tell an old layout from a new one without decoding the whole record.
+pub fn has_field(env: &Env, record: &Val, field: Symbol) -> bool {
+    Map::<Symbol, Val>::try_from_val(env, record)
+        .map(|fields| fields.contains_key(field))
+        .unwrap_or(false)
+}
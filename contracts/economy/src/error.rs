@@ -0,0 +1,68 @@
+use game_common::access::AccessError;
+use game_common::ledger::LedgerError;
+use game_common::math::MathError;
+use game_common::registry::RegistryError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    ResourceNotFound = 1,
+    InsufficientBalance = 2,
+    InvalidAmount = 3,
+
+    NotInitialized = 100,
+    AlreadyInitialized = 101,
+    Unauthorized = 102,
+
+    Overflow = 110,
+    DivisionByZero = 111,
+    OutOfDomain = 112,
+
+    RegistryNotSet = 120,
+    NotRegistered = 121,
+    UnknownResource = 122,
+    NotTradable = 123,
+}
+
+impl From<AccessError> for Error {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => Error::NotInitialized,
+            AccessError::AlreadyInitialized => Error::AlreadyInitialized,
+            AccessError::Unauthorized => Error::Unauthorized,
+        }
+    }
+}
+
+impl From<LedgerError> for Error {
+    fn from(error: LedgerError) -> Self {
+        match error {
+            LedgerError::InvalidAmount => Error::InvalidAmount,
+            LedgerError::InsufficientBalance => Error::InsufficientBalance,
+            LedgerError::Overflow => Error::Overflow,
+        }
+    }
+}
+
+impl From<MathError> for Error {
+    fn from(error: MathError) -> Self {
+        match error {
+            MathError::Overflow => Error::Overflow,
+            MathError::DivisionByZero => Error::DivisionByZero,
+            MathError::OutOfDomain => Error::OutOfDomain,
+        }
+    }
+}
+
+impl From<RegistryError> for Error {
+    fn from(error: RegistryError) -> Self {
+        match error {
+            RegistryError::RegistryNotSet => Error::RegistryNotSet,
+            RegistryError::NotRegistered => Error::NotRegistered,
+            RegistryError::UnknownResource => Error::UnknownResource,
+            RegistryError::NotTradable => Error::NotTradable,
+        }
+    }
+}
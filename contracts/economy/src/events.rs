@@ -0,0 +1,88 @@
+use game_common::events::{self, BOUGHT, CREDITED, DEBITED, PRICE, RESOURCE, SET, SOLD, TRANSFER};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::ResourcePrice;
+
+// (PRICE, SET): `resource` is now priced by `price`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceSet {
+    pub resource: Symbol,
+    pub price: ResourcePrice,
+}
+
+// (RESOURCE, CREDITED) and (RESOURCE, DEBITED): `amount` entered or left
+// the game in `account`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceChanged {
+    pub account: Address,
+    pub resource: Symbol,
+    pub amount: u64,
+}
+
+// (RESOURCE, TRANSFER): `amount` moved from `from` to `to`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transferred {
+    pub from: Address,
+    pub to: Address,
+    pub resource: Symbol,
+    pub amount: u64,
+}
+
+// (RESOURCE, BOUGHT) and (RESOURCE, SOLD): `amount` moved from `seller`
+// to `buyer` at `price` per unit
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sale {
+    pub buyer: Address,
+    pub seller: Address,
+    pub resource: Symbol,
+    pub amount: u64,
+    pub price: u64,
+}
+
+pub fn price_set(env: &Env, resource: &Symbol, price: &ResourcePrice) {
+    events::publish(
+        env,
+        PRICE,
+        SET,
+        PriceSet { resource: resource.clone(), price: price.clone() },
+    );
+}
+
+pub fn credited(env: &Env, account: &Address, resource: &Symbol, amount: u64) {
+    events::publish(
+        env,
+        RESOURCE,
+        CREDITED,
+        BalanceChanged { account: account.clone(), resource: resource.clone(), amount },
+    );
+}
+
+pub fn debited(env: &Env, account: &Address, resource: &Symbol, amount: u64) {
+    events::publish(
+        env,
+        RESOURCE,
+        DEBITED,
+        BalanceChanged { account: account.clone(), resource: resource.clone(), amount },
+    );
+}
+
+pub fn transferred(env: &Env, from: &Address, to: &Address, resource: &Symbol, amount: u64) {
+    events::publish(
+        env,
+        RESOURCE,
+        TRANSFER,
+        Transferred { from: from.clone(), to: to.clone(), resource: resource.clone(), amount },
+    );
+}
+
+pub fn bought(env: &Env, sale: Sale) {
+    events::publish(env, RESOURCE, BOUGHT, sale);
+}
+
+pub fn sold(env: &Env, sale: Sale) {
+    events::publish(env, RESOURCE, SOLD, sale);
+}
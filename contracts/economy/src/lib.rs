@@ -0,0 +1,381 @@
+#![no_std]
+mod error;
+pub mod events;
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+
+use events::Sale;
+use game_common::interfaces::Trade;
+use game_common::registry::Role;
+use game_common::{access, ledger, math, registry, storage, upgrade};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short,
+    Address, BytesN, Env, Map, Symbol, Vec,
+};
+
+// Layout version of the records this code writes
+pub const SCHEMA_VERSION: u32 = 2;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    ResourcePrice(Symbol),
+    ResourceMarket,
+    TransactionHistory(Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourcePrice {
+    pub base_price: u64,
+    pub volatility: u64,
+    pub last_update: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transaction {
+    pub timestamp: u64,
+    pub type_: Symbol,
+    pub resource: Symbol,
+    pub amount: u64,
+    pub price: u64,
+    pub counterparty: Address,
+}
+
+#[contract]
+pub struct EconomyContract;
+
+#[contractimpl]
+impl EconomyContract {
+    // Admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        access::initialize(&env, &admin)?;
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::transfer_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Ok(access::read_admin(&env)?)
+    }
+
+    pub fn set_operator(env: Env, operator: Address, enabled: bool) -> Result<(), Error> {
+        access::set_operator(&env, &operator, enabled)?;
+        Ok(())
+    }
+
+    pub fn is_operator(env: Env, account: Address) -> bool {
+        access::is_operator(&env, &account)
+    }
+
+    // Upgrades
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, wasm_hash)?;
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    // Registry
+    pub fn set_registry(env: Env, registry: Address) -> Result<(), Error> {
+        registry::set_registry(&env, &registry)?;
+        Ok(())
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, Error> {
+        Ok(registry::read_registry(&env)?)
+    }
+
+    // Bring storage written by older versions up to the current layout.
+    // Version 2 keeps resource prices in instance storage.
+    pub fn migrate(env: Env, resources: Vec<Symbol>) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        for resource in resources.iter() {
+            storage::move_to_instance::<_, ResourcePrice>(&env, &DataKey::ResourcePrice(resource));
+        }
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(())
+    }
+
+    // Resource Management
+    pub fn initialize_resource(
+        env: Env,
+        caller: Address,
+        resource: Symbol,
+        base_price: u64,
+    ) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        // Only tradable resources have a market price
+        registry::tradable_resource(&env, &resource)?;
+        let price = ResourcePrice {
+            base_price,
+            volatility: 10, // 10% volatility
+            last_update: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&DataKey::ResourcePrice(resource.clone()), &price);
+        events::price_set(&env, &resource, &price);
+
+        Ok(())
+    }
+
+    pub fn get_resource_price(env: Env, resource: Symbol) -> Result<u64, Error> {
+        let price = storage::read_moved::<_, ResourcePrice>(&env, &DataKey::ResourcePrice(resource))
+            .ok_or(Error::ResourceNotFound)?;
+        
+        // Calculate current price based on time and volatility:
+        // base * (1 + sin(hours passed) * volatility%)
+        let time_passed = env.ledger().timestamp().saturating_sub(price.last_update);
+        let hours = math::from_ratio(time_passed as i128, 3600)?;
+        let swing = math::mul(math::sin(hours)?, math::from_ratio(price.volatility as i128, 100)?)?;
+        let factor = math::add(math::SCALE, swing)?;
+        let current = math::to_int(math::mul(math::from_int(price.base_price as i128)?, factor)?);
+        u64::try_from(current).map_err(|_| Error::Overflow)
+    }
+
+    // Balances live in the shared ledger; every other contract reads and
+    // writes them through the entry points below
+    pub fn get_player_resources(env: Env, player: Address) -> Map<Symbol, u64> {
+        ledger::balances(&env, &player)
+    }
+
+    pub fn get_balance(env: Env, player: Address, resource: Symbol) -> u64 {
+        ledger::balance(&env, &player, &resource)
+    }
+
+    pub fn get_total_supply(env: Env, resource: Symbol) -> u64 {
+        ledger::supply(&env, &resource)
+    }
+
+    // Bring `amount` of `resource` into the game for `player`. Game contracts
+    // are made operators so they can pay out what players mine and earn.
+    pub fn credit(
+        env: Env,
+        caller: Address,
+        player: Address,
+        resource: Symbol,
+        amount: u64,
+    ) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        registry::resource(&env, &resource)?;
+        ledger::credit(&env, &player, &resource, amount)?;
+        events::credited(&env, &player, &resource, amount);
+
+        Ok(())
+    }
+
+    // Take `amount` of `resource` out of the game from `player`, who must
+    // have signed
+    pub fn debit(
+        env: Env,
+        caller: Address,
+        player: Address,
+        resource: Symbol,
+        amount: u64,
+    ) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        ledger::debit(&env, &player, &resource, amount)?;
+        events::debited(&env, &player, &resource, amount);
+
+        Ok(())
+    }
+
+    // Token Bridge
+    // Move `amount` of `resource` out of the ledger into the player's
+    // resource tokens. The Economy must be the token's minter.
+    pub fn withdraw(env: Env, player: Address, resource: Symbol, amount: u64) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let token = registry::resource_token(&env, &resource)?;
+        ledger::debit(&env, &player, &resource, amount)?;
+        token.mint(&env.current_contract_address(), &player, &i128::from(amount));
+        events::debited(&env, &player, &resource, amount);
+
+        Ok(())
+    }
+
+    // Burn `amount` of the player's resource tokens and credit them back to
+    // their ledger balance
+    pub fn deposit(env: Env, player: Address, resource: Symbol, amount: u64) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let token = registry::resource_token(&env, &resource)?;
+        token.consume(&env.current_contract_address(), &player, &i128::from(amount));
+        ledger::credit(&env, &player, &resource, amount)?;
+        events::credited(&env, &player, &resource, amount);
+
+        Ok(())
+    }
+
+    // Resource Transactions
+    pub fn transfer_resources(
+        env: Env,
+        from: Address,
+        to: Address,
+        resource: Symbol,
+        amount: u64,
+    ) -> Result<(), Error> {
+        from.require_auth();
+        storage::extend_instance(&env);
+
+        registry::tradable_resource(&env, &resource)?;
+        ledger::transfer(&env, &from, &to, &resource, amount)?;
+
+        // Record transaction
+        let transaction = Transaction {
+            timestamp: env.ledger().timestamp(),
+            type_: symbol_short!("TRANSFER"),
+            resource: resource.clone(),
+            amount,
+            price: 0,
+            counterparty: to.clone(),
+        };
+
+        let mut from_history = Self::get_transaction_history(env.clone(), from.clone());
+        from_history.push_back(transaction);
+        storage::write_persistent(&env, &DataKey::TransactionHistory(from.clone()), &from_history);
+
+        events::transferred(&env, &from, &to, &resource, amount);
+
+        Ok(())
+    }
+
+    // Swap both legs of an accepted trade offer; either leg failing leaves
+    // both balances untouched. Only the contract registered as Trading may
+    // settle, since the seller agreed to the trade when offering it there,
+    // and the buyer must sign.
+    pub fn settle_trade(env: Env, caller: Address, trade: Trade) -> Result<(), Error> {
+        caller.require_auth();
+        if caller != registry::lookup(&env, Role::Trading)? {
+            return Err(Error::Unauthorized);
+        }
+        trade.buyer.require_auth();
+        storage::extend_instance(&env);
+
+        registry::tradable_resource(&env, &trade.sell_resource)?;
+        registry::tradable_resource(&env, &trade.buy_resource)?;
+        ledger::transfer(&env, &trade.seller, &trade.buyer, &trade.sell_resource, trade.sell_amount)?;
+        ledger::transfer(&env, &trade.buyer, &trade.seller, &trade.buy_resource, trade.buy_amount)?;
+
+        for (player, counterparty, resource, amount) in [
+            (&trade.seller, &trade.buyer, &trade.sell_resource, trade.sell_amount),
+            (&trade.buyer, &trade.seller, &trade.buy_resource, trade.buy_amount),
+        ] {
+            let transaction = Transaction {
+                timestamp: env.ledger().timestamp(),
+                type_: symbol_short!("TRADE"),
+                resource: resource.clone(),
+                amount,
+                price: 0,
+                counterparty: counterparty.clone(),
+            };
+            let mut history = Self::get_transaction_history(env.clone(), player.clone());
+            history.push_back(transaction);
+            storage::write_persistent(&env, &DataKey::TransactionHistory(player.clone()), &history);
+            events::transferred(&env, player, counterparty, resource, amount);
+        }
+
+        Ok(())
+    }
+
+    pub fn buy_resources(
+        env: Env,
+        buyer: Address,
+        seller: Address,
+        resource: Symbol,
+        amount: u64,
+    ) -> Result<(), Error> {
+        buyer.require_auth();
+        seller.require_auth();
+        storage::extend_instance(&env);
+
+        registry::tradable_resource(&env, &resource)?;
+        let price = Self::get_resource_price(env.clone(), resource.clone())?;
+        ledger::transfer(&env, &seller, &buyer, &resource, amount)?;
+
+        // Record transaction
+        let transaction = Transaction {
+            timestamp: env.ledger().timestamp(),
+            type_: symbol_short!("BUY"),
+            resource: resource.clone(),
+            amount,
+            price,
+            counterparty: seller.clone(),
+        };
+
+        let mut buyer_history = Self::get_transaction_history(env.clone(), buyer.clone());
+        buyer_history.push_back(transaction);
+        storage::write_persistent(&env, &DataKey::TransactionHistory(buyer.clone()), &buyer_history);
+
+        events::bought(&env, Sale { buyer, seller, resource, amount, price });
+
+        Ok(())
+    }
+
+    pub fn sell_resources(
+        env: Env,
+        seller: Address,
+        buyer: Address,
+        resource: Symbol,
+        amount: u64,
+    ) -> Result<(), Error> {
+        seller.require_auth();
+        buyer.require_auth();
+        storage::extend_instance(&env);
+
+        registry::tradable_resource(&env, &resource)?;
+        let price = Self::get_resource_price(env.clone(), resource.clone())?;
+        ledger::transfer(&env, &seller, &buyer, &resource, amount)?;
+
+        // Record transaction
+        let transaction = Transaction {
+            timestamp: env.ledger().timestamp(),
+            type_: symbol_short!("SELL"),
+            resource: resource.clone(),
+            amount,
+            price,
+            counterparty: buyer.clone(),
+        };
+
+        let mut seller_history = Self::get_transaction_history(env.clone(), seller.clone());
+        seller_history.push_back(transaction);
+        storage::write_persistent(&env, &DataKey::TransactionHistory(seller.clone()), &seller_history);
+
+        events::sold(&env, Sale { buyer, seller, resource, amount, price });
+
+        Ok(())
+    }
+
+    // Transaction History
+    pub fn get_transaction_history(env: Env, player: Address) -> Vec<Transaction> {
+        storage::read_persistent(&env, &DataKey::TransactionHistory(player)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Market Analysis
+    pub fn get_market_trend(_env: Env, _resource: Symbol) -> i64 {
+        // This would analyze recent transactions to determine market trend
+        // Returns a value between -100 and 100 indicating bearish to bullish trend
+        0 // Placeholder
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,259 @@
+#![cfg(test)]
+
+use super::*;
+use game_common::interfaces::Trade;
+use game_common::ledger::LedgerError;
+use game_common::registry::Role;
+use game_common::resources::{Rarity, ResourceInfo};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    vec, Address, Env, IntoVal, String,
+};
+
+fn setup(env: &Env) -> (Address, EconomyContractClient<'_>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, EconomyContract);
+    let client = EconomyContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    // Resource symbols are checked against the registry
+    let registry = env.register_contract(None, ::registry::Registry);
+    ::registry::RegistryClient::new(env, &registry).initialize(&admin);
+    client.set_registry(&registry);
+    (admin, client)
+}
+
+#[test]
+fn test_unknown_resource_price() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+
+    assert_eq!(
+        client.try_get_resource_price(&symbol_short!("IRON")),
+        Err(Ok(Error::ResourceNotFound))
+    );
+
+    client.initialize_resource(&admin, &symbol_short!("IRON"), &100);
+    assert_eq!(client.get_resource_price(&symbol_short!("IRON")), 100);
+}
+
+#[test]
+fn test_transfer_resources() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let iron = symbol_short!("IRON");
+
+    assert_eq!(
+        client.try_transfer_resources(&from, &to, &iron, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_transfer_resources(&from, &to, &iron, &10),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    client.credit(&admin, &from, &iron, &10);
+
+    client.transfer_resources(&from, &to, &iron, &4);
+    assert_eq!(client.get_player_resources(&from).get(iron.clone()), Some(6));
+    assert_eq!(client.get_player_resources(&to).get(iron), Some(4));
+}
+
+#[test]
+fn test_transfers_require_auth() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    let iron = symbol_short!("IRON");
+
+    client.initialize_resource(&admin, &iron, &100);
+    client.credit(&admin, &from, &iron, &10);
+
+    env.set_auths(&[]);
+    assert!(client.try_transfer_resources(&from, &to, &iron, &1).is_err());
+    assert!(client.try_buy_resources(&to, &from, &iron, &1).is_err());
+    assert!(client.try_sell_resources(&from, &to, &iron, &1).is_err());
+    assert_eq!(client.get_player_resources(&from).get(iron), Some(10));
+}
+
+#[test]
+fn test_initialize_resource_requires_admin_or_operator() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_initialize_resource(&stranger, &symbol_short!("IRON"), &1),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_resource_price_follows_volatility_curve() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let iron = symbol_short!("IRON");
+
+    client.initialize_resource(&admin, &iron, &1_000);
+
+    // base * (1 + sin(hours) / 10), pinned at fixed points of the curve
+    let mut prices = Vec::new(&env);
+    for hours in [0u64, 1, 2, 4, 5, 24] {
+        env.ledger().with_mut(|ledger| ledger.timestamp = hours * 3600);
+        prices.push_back(client.get_resource_price(&iron));
+    }
+    assert_eq!(prices, vec![&env, 1_000, 1_084, 1_090, 924, 904, 909]);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 5400);
+    assert_eq!(client.get_resource_price(&iron), 1_099);
+}
+
+#[test]
+fn test_credit_and_debit_track_supply() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let game = Address::generate(&env);
+    let player = Address::generate(&env);
+    let iron = symbol_short!("IRON");
+
+    assert_eq!(
+        client.try_credit(&game, &player, &iron, &10),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_operator(&game, &true);
+
+    client.credit(&game, &player, &iron, &10);
+    client.credit(&game, &player, &iron, &5);
+    assert_eq!(client.get_balance(&player, &iron), 15);
+    assert_eq!(client.get_player_resources(&player).len(), 1);
+    assert_eq!(client.get_total_supply(&iron), 15);
+
+    client.debit(&game, &player, &iron, &15);
+    assert_eq!(client.get_balance(&player, &iron), 0);
+    assert_eq!(client.get_total_supply(&iron), 0);
+    assert_eq!(
+        client.try_debit(&game, &player, &iron, &1),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_credit(&admin, &player, &iron, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_ledger_error_codes() {
+    // Other contracts read ledger failures back from these codes
+    assert_eq!(LedgerError::InsufficientBalance.code(), Error::InsufficientBalance as u32);
+    assert_eq!(LedgerError::InvalidAmount.code(), Error::InvalidAmount as u32);
+    assert_eq!(LedgerError::Overflow.code(), Error::Overflow as u32);
+}
+
+#[test]
+fn test_settle_trade_swaps_both_legs() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let trading = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (water, iron) = (symbol_short!("WATER"), symbol_short!("IRON"));
+    let trade = Trade {
+        seller: seller.clone(),
+        buyer: buyer.clone(),
+        sell_resource: water.clone(),
+        sell_amount: 200,
+        buy_resource: iron.clone(),
+        buy_amount: 50,
+    };
+
+    // Only the registered Trading contract settles, not other operators
+    let registry = ::registry::RegistryClient::new(&env, &client.get_registry());
+    client.set_operator(&operator, &true);
+    client.credit(&operator, &seller, &water, &200);
+    client.credit(&operator, &buyer, &iron, &40);
+    assert_eq!(
+        client.try_settle_trade(&trading, &trade),
+        Err(Ok(Error::NotRegistered))
+    );
+    registry.set_contract(&Role::Trading, &trading);
+    assert_eq!(
+        client.try_settle_trade(&operator, &trade),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // A short leg leaves both balances untouched
+    assert_eq!(
+        client.try_settle_trade(&trading, &trade),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(client.get_balance(&seller, &water), 200);
+
+    client.credit(&operator, &buyer, &iron, &10);
+    assert_eq!(
+        client.try_settle_trade(&seller, &trade),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // The buyer has to sign as well as Trading
+    env.mock_auths(&[MockAuth {
+        address: &trading,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "settle_trade",
+            args: (trading.clone(), trade.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_settle_trade(&trading, &trade).is_err());
+    assert_eq!(client.get_balance(&seller, &water), 200);
+
+    env.mock_all_auths();
+    client.settle_trade(&trading, &trade);
+    assert_eq!(client.get_balance(&seller, &water), 0);
+    assert_eq!(client.get_balance(&seller, &iron), 50);
+    assert_eq!(client.get_balance(&buyer, &water), 200);
+    assert_eq!(client.get_balance(&buyer, &iron), 0);
+    assert_eq!(client.get_transaction_history(&buyer).get_unchecked(0).counterparty, seller);
+}
+
+#[test]
+fn test_resources_are_checked_against_registry() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let player = Address::generate(&env);
+    let other = Address::generate(&env);
+    let relic = symbol_short!("RELIC");
+
+    assert_eq!(
+        client.try_credit(&admin, &player, &symbol_short!("IRNO"), &10),
+        Err(Ok(Error::UnknownResource))
+    );
+
+    // Untradable resources can be held but not priced or moved between players
+    let registry = ::registry::RegistryClient::new(&env, &client.get_registry());
+    registry.set_resource(
+        &relic,
+        &ResourceInfo {
+            name: String::from_str(&env, "Relic"),
+            rarity: Rarity::Exotic,
+            unit_mass: 1,
+            tradable: false,
+        },
+    );
+    client.credit(&admin, &player, &relic, &1);
+    assert_eq!(
+        client.try_initialize_resource(&admin, &relic, &100),
+        Err(Ok(Error::NotTradable))
+    );
+    assert_eq!(
+        client.try_transfer_resources(&player, &other, &relic, &1),
+        Err(Ok(Error::NotTradable))
+    );
+    assert_eq!(client.get_balance(&player, &relic), 1);
+}
@@ -0,0 +1,226 @@
+use economy::{EconomyContract, EconomyContractClient};
+use game_common::events;
+use game_common::storage::LEDGER_CLOSE_TIME;
+use game_common::registry::Role;
+use game_common::resources::{ENERGY, GOLD, IRON, WATER};
+use mission::{MissionContract, MissionContractClient};
+use missions::{MissionSystem, MissionSystemClient};
+use registry::{Registry, RegistryClient};
+use resource_token::{ResourceToken, ResourceTokenClient};
+use shipyard::{Blueprint, ShipyardContract, ShipyardContractClient, UpgradeCurve};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token::TokenClient,
+    Address, Env, IntoVal, Map, String, Symbol, Val, Vec,
+};
+use space_game::{SpaceGame, SpaceGameClient};
+use star_system::{StarSystem, StarSystemClient};
+use trading::{Trading, TradingClient};
+
+// Every game contract registered in one Env, initialized with a shared admin,
+// wired together through the registry and seeded with the starting world
+pub struct Harness {
+    pub env: Env,
+    pub admin: Address,
+    pub registry: RegistryClient<'static>,
+    pub space_game: SpaceGameClient<'static>,
+    pub star_system: StarSystemClient<'static>,
+    pub missions: MissionSystemClient<'static>,
+    pub trading: TradingClient<'static>,
+    pub economy: EconomyContractClient<'static>,
+    pub shipyard: ShipyardContractClient<'static>,
+    pub mission: MissionContractClient<'static>,
+}
+
+impl Harness {
+    pub fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+
+        let registry = RegistryClient::new(&env, &env.register_contract(None, Registry));
+        let space_game = SpaceGameClient::new(&env, &env.register_contract(None, SpaceGame));
+        let star_system = StarSystemClient::new(&env, &env.register_contract(None, StarSystem));
+        let missions = MissionSystemClient::new(&env, &env.register_contract(None, MissionSystem));
+        let trading = TradingClient::new(&env, &env.register_contract(None, Trading));
+        let economy =
+            EconomyContractClient::new(&env, &env.register_contract(None, EconomyContract));
+        let shipyard =
+            ShipyardContractClient::new(&env, &env.register_contract(None, ShipyardContract));
+        let mission =
+            MissionContractClient::new(&env, &env.register_contract(None, MissionContract));
+
+        registry.initialize(&admin);
+        space_game.initialize(&admin);
+        star_system.initialize(&admin);
+        missions.initialize(&admin);
+        trading.initialize(&admin);
+        economy.initialize(&admin);
+        shipyard.initialize(&admin);
+        mission.initialize(&admin);
+
+        for (role, address) in [
+            (Role::SpaceGame, &space_game.address),
+            (Role::StarSystem, &star_system.address),
+            (Role::Missions, &missions.address),
+            (Role::Trading, &trading.address),
+            (Role::Economy, &economy.address),
+            (Role::Shipyard, &shipyard.address),
+            (Role::Mission, &mission.address),
+        ] {
+            registry.set_contract(&role, address);
+        }
+        space_game.set_registry(&registry.address);
+        shipyard.set_registry(&registry.address);
+        mission.set_registry(&registry.address);
+        trading.set_registry(&registry.address);
+        star_system.set_registry(&registry.address);
+        missions.set_registry(&registry.address);
+        economy.set_registry(&registry.address);
+
+        // Game contracts pay out into the economy's balance ledger, trades
+        // settle in it, and the shipyard mines star deposits and crafts from
+        // player balances
+        economy.set_operator(&space_game.address, &true);
+        economy.set_operator(&mission.address, &true);
+        economy.set_operator(&shipyard.address, &true);
+        star_system.set_operator(&shipyard.address, &true);
+
+        // One token per resource, issued by the Economy for withdrawn balances
+        for (resource, code, name) in [
+            (IRON, "IRON", "Iron"),
+            (WATER, "WATER", "Water"),
+            (ENERGY, "ENERGY", "Energy"),
+            (GOLD, "GOLD", "Gold"),
+        ] {
+            let token =
+                ResourceTokenClient::new(&env, &env.register_contract(None, ResourceToken));
+            token.initialize(
+                &admin,
+                &0,
+                &String::from_str(&env, name),
+                &String::from_str(&env, code),
+            );
+            token.set_minter(&economy.address, &true);
+            registry.set_contract(&Role::ResourceToken(resource), &token.address);
+        }
+
+        // Starting world
+        star_system.initialize_system(&admin);
+        missions.initialize_missions(&admin);
+        for (resource, base_price) in [(IRON, 10), (WATER, 5), (ENERGY, 20), (GOLD, 100)] {
+            economy.initialize_resource(&admin, &resource, &base_price);
+        }
+        // (type, health, cargo, speed, fuel, mining rate, module slots, iron, energy)
+        for (ship_type, health, cargo, speed, fuel, mining, slots, iron, energy) in [
+            ("EXPLORER", 80, 400, 20, 400, 50, 3, 200, 50),
+            ("MINER", 120, 1_000, 8, 200, 100, 2, 300, 50),
+            ("REFINERY", 200, 2_000, 5, 150, 25, 4, 400, 100),
+        ] {
+            let mut build_cost = Map::new(&env);
+            build_cost.set(IRON, iron);
+            build_cost.set(ENERGY, energy);
+            let blueprint = Blueprint {
+                health,
+                cargo_capacity: cargo,
+                speed,
+                fuel_capacity: fuel,
+                mining_rate: mining,
+                module_slots: slots,
+                build_cost,
+                build_time: 0,
+                upgrade: UpgradeCurve {
+                    health: health / 5,
+                    cargo_capacity: cargo / 5,
+                    fuel_capacity: fuel / 5,
+                    mining_rate: mining / 2,
+                    max_level: 10,
+                },
+            };
+            shipyard.set_blueprint(&Symbol::new(&env, ship_type), &blueprint);
+        }
+
+        Harness {
+            env,
+            admin,
+            registry,
+            space_game,
+            star_system,
+            missions,
+            trading,
+            economy,
+            shipyard,
+            mission,
+        }
+    }
+
+    // A fresh account, onboarded into the game
+    pub fn player(&self) -> Address {
+        let player = Address::generate(&self.env);
+        self.space_game.initialize_player(&player);
+        player
+    }
+
+    // The SEP-41 token issued for `resource`
+    pub fn token(&self, resource: &Symbol) -> TokenClient<'static> {
+        let address = self.registry.get_contract(&Role::ResourceToken(resource.clone()));
+        TokenClient::new(&self.env, &address)
+    }
+
+    pub fn advance_time(&self, seconds: u64) {
+        self.env.ledger().with_mut(|ledger| {
+            ledger.timestamp += seconds;
+            ledger.sequence_number += (seconds / LEDGER_CLOSE_TIME) as u32;
+        });
+    }
+
+    pub fn now(&self) -> u64 {
+        self.env.ledger().timestamp()
+    }
+
+    // Order and claim a ship of `ship_type` for `owner`, crediting its cost
+    // first so the owner's balances end up as they were
+    pub fn build_ship(&self, owner: &Address, ship_type: &Symbol) -> u64 {
+        let blueprint = self.shipyard.get_blueprint(ship_type);
+        for (resource, amount) in blueprint.build_cost.iter() {
+            self.seed_balance(owner, &resource, amount);
+        }
+        let build_id = self.shipyard.order_ship(owner, &Symbol::new(&self.env, "NOVA"), ship_type);
+        self.advance_time(blueprint.build_time);
+        self.shipyard.claim_ship(owner, &build_id)
+    }
+
+    // Credit an economy balance as the admin, standing in for resources
+    // earned elsewhere in the game
+    pub fn seed_balance(&self, player: &Address, resource: &Symbol, amount: u64) {
+        self.economy.credit(&self.admin, player, resource, &amount);
+    }
+
+    pub fn balance(&self, player: &Address, resource: &Symbol) -> u64 {
+        self.economy.get_balance(player, resource)
+    }
+
+    // Events `contract` has published so far, as (topics, data) pairs
+    pub fn events(&self, contract: &Address) -> Vec<(Vec<Val>, Val)> {
+        let mut events = Vec::new(&self.env);
+        for (source, topics, data) in self.env.events().all().iter() {
+            if &source == contract {
+                events.push_back((topics, data));
+            }
+        }
+        events
+    }
+
+    // Assert `contract` has published (namespace, action) with `payload`
+    pub fn assert_event<D>(&self, contract: &Address, namespace: Symbol, action: Symbol, payload: D)
+    where
+        D: IntoVal<Env, Val>,
+    {
+        let topics = (namespace, action, events::EVENT_VERSION).into_val(&self.env);
+        let expected: (Vec<Val>, Val) = (topics, payload.into_val(&self.env));
+        assert!(
+            self.events(contract).contains(&expected),
+            "expected event was not published"
+        );
+    }
+}
@@ -0,0 +1,8 @@
+#![no_std]
+
+// End-to-end scenarios that run every game contract side by side in one Env.
+
+#[cfg(test)]
+mod harness;
+#[cfg(test)]
+mod test;
@@ -0,0 +1,927 @@
+#![cfg(test)]
+
+use crate::harness::Harness;
+use game_common::events;
+use game_common::registry::{self, RegistryError, Role};
+use game_common::resources::{Rarity, ResourceInfo, ENERGY, GOLD, IRON, WATER};
+use shipyard::{Blueprint, BuildStatus, Module, Recipe, ShipStats, SlotType, UpgradeCurve};
+use soroban_sdk::{
+    map, symbol_short, testutils::Address as _, vec, Address, Map, String, Symbol,
+};
+
+#[test]
+fn test_player_journey() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let trader = game.player();
+
+    // Onboarding: the pilot pays for a hull and launches it from the shipyard
+    game.seed_balance(&pilot, &IRON, 200);
+    game.seed_balance(&pilot, &ENERGY, 50);
+    let build_id = game
+        .space_game
+        .create_ship(&pilot, &symbol_short!("NOVA"), &symbol_short!("EXPLORER"));
+    assert_eq!(game.economy.get_player_resources(&pilot), Map::new(env));
+    let ship_id = game.shipyard.claim_ship(&pilot, &build_id);
+    let ship = game.shipyard.get_ship(&ship_id);
+    assert_eq!(ship.built_at, game.now());
+    game.assert_event(
+        &game.shipyard.address,
+        events::SHIP,
+        events::CREATED,
+        shipyard::events::ShipChanged { ship },
+    );
+
+    // Exploration
+    game.advance_time(3_600);
+    let barnard = Symbol::new(env, "BARNARDS_STAR");
+    let proxima = Symbol::new(env, "PROXIMA_CENTAURI");
+    assert_eq!(
+        game.star_system.try_discover_star(&pilot, &ship_id, &barnard),
+        Err(Ok(star_system::Error::OutOfScanRange))
+    );
+    assert!(game.star_system.discover_star(&pilot, &ship_id, &proxima));
+    assert_eq!(game.star_system.get_discovered_by(&pilot, &0), vec![env, proxima.clone()]);
+    assert_eq!(game.star_system.get_first_discovery(&proxima).player, pilot);
+    game.assert_event(
+        &game.star_system.address,
+        events::STAR,
+        events::DISCOVERY,
+        star_system::events::StarDiscovered { player: pilot.clone(), star: proxima, first: true },
+    );
+    game.star_system.add_resource(&game.admin, &barnard, &WATER, &5_000);
+    assert_eq!(game.star_system.get_resource_amount(&barnard, &WATER), 5_000);
+    game.assert_event(
+        &game.star_system.address,
+        events::DEPOSIT,
+        events::REPLENISH,
+        star_system::events::ResourceReplenished {
+            star: barnard.clone(),
+            resource: WATER,
+            amount: 5_000,
+        },
+    );
+
+    // Mining draws down the star into the ship's hold
+    let alpha = Symbol::new(env, "ALPHA_CENTAURI");
+    game.star_system.add_resource(&game.admin, &alpha, &WATER, &1_000);
+    assert_eq!(game.shipyard.mine(&pilot, &ship_id, &alpha, &WATER), 50);
+    assert_eq!(game.shipyard.get_ship(&ship_id).resources, map![env, (WATER, 50)]);
+    assert_eq!(game.star_system.get_resource_amount(&alpha, &WATER), 950);
+    game.assert_event(
+        &game.shipyard.address,
+        events::RESOURCE,
+        events::COLLECTED,
+        shipyard::events::ResourceMined {
+            ship_id,
+            star: alpha.clone(),
+            resource: WATER,
+            amount: 50,
+        },
+    );
+    game.seed_balance(&pilot, &WATER, 1_200);
+    game.seed_balance(&trader, &IRON, 300);
+
+    // Trading: list an offer and have it taken; accepting settles both legs.
+    // Each side trades from a ship docked at a star.
+    let trader_ship = game.build_ship(&trader, &symbol_short!("MINER"));
+    let offer_id = game.trading.create_offer(&pilot, &ship_id, &WATER, &200, &IRON, &50);
+    assert_eq!(game.trading.get_active_offers().len(), 1);
+    game.assert_event(
+        &game.trading.address,
+        events::OFFER,
+        events::CREATED,
+        trading::events::OfferCreated { offer: game.trading.get_offer_details(&offer_id) },
+    );
+    let broke = game.player();
+    let broke_ship = game.build_ship(&broke, &symbol_short!("MINER"));
+    assert_eq!(
+        game.trading.try_accept_offer(&broke, &broke_ship, &offer_id),
+        Err(Ok(trading::Error::InsufficientBalance))
+    );
+    assert!(game.trading.get_offer_details(&offer_id).active);
+
+    game.advance_time(1_800);
+    game.trading.accept_offer(&trader, &trader_ship, &offer_id);
+    assert!(!game.trading.get_offer_details(&offer_id).active);
+    game.assert_event(
+        &game.trading.address,
+        events::OFFER,
+        events::ACCEPTED,
+        trading::events::OfferAccepted { buyer: trader.clone(), offer_id },
+    );
+    assert_eq!(game.trading.get_active_offers().len(), 0);
+    game.assert_event(
+        &game.economy.address,
+        events::RESOURCE,
+        events::TRANSFER,
+        economy::events::Transferred {
+            from: trader.clone(),
+            to: pilot.clone(),
+            resource: IRON,
+            amount: 50,
+        },
+    );
+
+    assert_eq!(game.balance(&pilot, &WATER), 1_000);
+    assert_eq!(game.balance(&pilot, &IRON), 50);
+    assert_eq!(game.balance(&trader, &WATER), 200);
+    assert_eq!(game.balance(&trader, &IRON), 250);
+
+    let history = game.economy.get_transaction_history(&pilot);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get_unchecked(0).counterparty, trader);
+    assert_eq!(history.get_unchecked(0).timestamp, game.now());
+
+    // Mission completion: the collector mission needs 1000 water
+    let collector = Symbol::new(env, "RESOURCE_COLLECTOR");
+    game.missions.check_mission_completion(&pilot, &collector);
+    assert_eq!(game.missions.get_player_missions(&pilot), vec![env, collector.clone()]);
+    game.assert_event(
+        &game.missions.address,
+        events::MISSION,
+        events::COMPLETED,
+        missions::events::MissionCompleted { player: pilot.clone(), mission_id: collector },
+    );
+
+    let mission_id = game.mission.create_mission(
+        &game.admin,
+        &symbol_short!("SURVEY"),
+        &symbol_short!("SCAN"),
+        &mission::Reward { resource: ENERGY, amount: 100 },
+        &mission::Requirements { stars: 1, resources: Map::new(env) },
+    );
+    let energy = game.balance(&pilot, &ENERGY);
+    game.mission.complete_mission(&pilot, &mission_id);
+    assert_eq!(game.balance(&pilot, &ENERGY), energy + 100);
+    game.assert_event(
+        &game.mission.address,
+        events::MISSION,
+        events::COMPLETED,
+        mission::events::MissionCompleted { player: pilot.clone(), mission_id },
+    );
+    assert_eq!(game.mission.get_player_missions(&pilot), vec![env, mission_id]);
+
+    // A mission needing two discovered stars stays out of reach
+    let mission_id = game.mission.create_mission(
+        &game.admin,
+        &symbol_short!("CHART"),
+        &symbol_short!("SCAN"),
+        &mission::Reward { resource: ENERGY, amount: 100 },
+        &mission::Requirements { stars: 2, resources: Map::new(env) },
+    );
+    assert_eq!(
+        game.mission.try_complete_mission(&pilot, &mission_id),
+        Err(Ok(mission::Error::RequirementsNotMet))
+    );
+}
+
+#[test]
+fn test_mission_requirements_follow_balances() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let trader = game.player();
+    let collector = Symbol::new(env, "RESOURCE_COLLECTOR");
+
+    game.seed_balance(&pilot, &WATER, 999);
+    assert_eq!(
+        game.missions.try_check_mission_completion(&pilot, &collector),
+        Err(Ok(missions::Error::RequirementsNotMet))
+    );
+
+    // Only what the ledger holds counts, so balances moved away no longer
+    // meet the requirement
+    game.seed_balance(&pilot, &WATER, 1);
+    game.economy.transfer_resources(&pilot, &trader, &WATER, &500);
+    assert_eq!(
+        game.missions.try_check_mission_completion(&pilot, &collector),
+        Err(Ok(missions::Error::RequirementsNotMet))
+    );
+
+    game.economy.transfer_resources(&trader, &pilot, &WATER, &500);
+    game.missions.check_mission_completion(&pilot, &collector);
+    assert_eq!(game.missions.get_player_missions(&pilot), vec![env, collector]);
+}
+
+#[test]
+fn test_prices_move_with_ledger_time() {
+    let game = Harness::new();
+
+    // 20 * (1 + sin(hours) / 10), truncated
+    let mut prices = vec![&game.env];
+    for _ in 0..4 {
+        prices.push_back(game.economy.get_resource_price(&ENERGY));
+        game.advance_time(3_600);
+    }
+    assert_eq!(prices, vec![&game.env, 20, 21, 21, 20]);
+    assert_eq!(game.env.ledger().sequence(), 4 * 3_600 / 5);
+}
+
+#[test]
+fn test_contracts_resolve_each_other_through_registry() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    game.seed_balance(&pilot, &IRON, 40);
+
+    // Lookups run as the shipyard, the way its entry points will
+    env.as_contract(&game.shipyard.address, || {
+        assert_eq!(registry::read_registry(env), Ok(game.registry.address.clone()));
+        assert_eq!(registry::lookup(env, Role::Trading), Ok(game.trading.address.clone()));
+
+        let economy = registry::economy(env).unwrap();
+        assert_eq!(economy.get_resource_price(&IRON), 10);
+        assert_eq!(economy.get_player_resources(&pilot), map![env, (IRON, 40)]);
+
+        let star_system = registry::star_system(env).unwrap();
+        assert_eq!(star_system.get_all_stars().len(), 3);
+
+        assert_eq!(registry::resource(env, &IRON).unwrap().unit_mass, 2);
+        assert_eq!(
+            registry::resource(env, &symbol_short!("IRNO")),
+            Err(RegistryError::UnknownResource)
+        );
+    });
+
+    // Swapping a contract is a single registry update
+    let relocated = Address::generate(env);
+    game.registry.set_contract(&Role::Economy, &relocated);
+    game.registry.remove_contract(&Role::StarSystem);
+    env.as_contract(&game.mission.address, || {
+        assert_eq!(registry::lookup(env, Role::Economy), Ok(relocated.clone()));
+        assert_eq!(
+            registry::lookup(env, Role::StarSystem),
+            Err(RegistryError::NotRegistered)
+        );
+    });
+    // Resource tokens never look anything up
+    env.as_contract(&game.token(&IRON).address, || {
+        assert_eq!(registry::read_registry(env), Err(RegistryError::RegistryNotSet));
+    });
+}
+
+#[test]
+fn test_resources_move_as_standard_tokens() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let trader = game.player();
+    let iron = game.token(&IRON);
+    assert_eq!(iron.symbol(), String::from_str(env, "IRON"));
+
+    // Ledger balances become tokens when withdrawn through the Economy
+    game.seed_balance(&pilot, &IRON, 500);
+    game.economy.withdraw(&pilot, &IRON, &400);
+    assert_eq!(iron.balance(&pilot), 400);
+    assert_eq!(game.balance(&pilot, &IRON), 100);
+    assert_eq!(game.economy.get_total_supply(&IRON), 100);
+    assert_eq!(
+        game.economy.try_withdraw(&pilot, &IRON, &101),
+        Err(Ok(economy::Error::InsufficientBalance))
+    );
+
+    // Anyone else moves them with the SEP-41 interface
+    iron.transfer(&pilot, &trader, &150);
+    iron.approve(&pilot, &game.trading.address, &50, &(env.ledger().sequence() + 100));
+    iron.transfer_from(&game.trading.address, &pilot, &trader, &50);
+    assert_eq!(iron.balance(&pilot), 200);
+    assert_eq!(iron.balance(&trader), 200);
+    assert_eq!(game.token(&GOLD).balance(&pilot), 0);
+
+    // Deposited tokens are burned and spendable in the game again
+    game.economy.deposit(&trader, &IRON, &120);
+    assert_eq!(iron.balance(&trader), 80);
+    assert_eq!(game.balance(&trader, &IRON), 120);
+    assert!(game.economy.try_deposit(&trader, &IRON, &81).is_err());
+    game.economy.transfer_resources(&trader, &pilot, &IRON, &20);
+    assert_eq!(game.balance(&pilot, &IRON), 120);
+
+    let stranger = Address::generate(env);
+    let token = resource_token::ResourceTokenClient::new(env, &iron.address);
+    assert_eq!(
+        token.try_mint(&stranger, &stranger, &1),
+        Err(Ok(resource_token::Error::NotMinter))
+    );
+}
+
+#[test]
+fn test_balances_have_one_source_of_truth() {
+    let game = Harness::new();
+    let env = &game.env;
+    let players = [game.player(), game.player(), game.player()];
+    let held = |resource: &Symbol| -> u64 {
+        players.iter().map(|player| game.balance(player, resource)).sum()
+    };
+
+    game.seed_balance(&players[0], &IRON, 600);
+    game.seed_balance(&players[0], &IRON, 400);
+    game.seed_balance(&players[1], &GOLD, 10);
+    assert_eq!(game.economy.get_player_resources(&players[0]), map![env, (IRON, 1_000)]);
+    assert_eq!(game.economy.get_total_supply(&IRON), 1_000);
+
+    // Trades and transfers move balances without creating or destroying any
+    game.economy.transfer_resources(&players[0], &players[1], &IRON, &300);
+    game.economy.buy_resources(&players[2], &players[1], &IRON, &100);
+    game.economy.sell_resources(&players[1], &players[2], &GOLD, &4);
+    assert_eq!(
+        game.economy.try_transfer_resources(&players[2], &players[0], &IRON, &101),
+        Err(Ok(economy::Error::InsufficientBalance))
+    );
+    for resource in [IRON, GOLD] {
+        assert_eq!(held(&resource), game.economy.get_total_supply(&resource));
+    }
+    assert_eq!(game.space_game.get_player_resources(&players[1]), map![env, (IRON, 200), (GOLD, 6)]);
+
+    // Spending takes resources out of circulation
+    game.economy.debit(&game.admin, &players[2], &IRON, &100);
+    assert_eq!(game.economy.get_total_supply(&IRON), 900);
+    assert_eq!(held(&IRON), 900);
+}
+
+#[test]
+fn test_mining_is_capped_by_ship_and_deposit() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let alpha = Symbol::new(env, "ALPHA_CENTAURI");
+    let barnard = Symbol::new(env, "BARNARDS_STAR");
+    let ship_id = game.build_ship(&pilot, &symbol_short!("MINER"));
+    let mine = |resource: &Symbol| game.shipyard.try_mine(&pilot, &ship_id, &alpha, resource);
+    let rate = game.shipyard.get_ship(&ship_id).mining_rate;
+    assert_eq!(rate, 100);
+
+    // Only stars the ship is at can be mined
+    game.star_system.add_resource(&game.admin, &barnard, &IRON, &5_000);
+    assert_eq!(
+        game.shipyard.try_mine(&pilot, &ship_id, &barnard, &IRON),
+        Err(Ok(shipyard::Error::ShipNotAtStar))
+    );
+
+    // Each run yields at most the ship's mining rate, then the drills cool down
+    game.star_system.add_resource(&game.admin, &alpha, &GOLD, &150);
+    assert_eq!(mine(&GOLD), Ok(Ok(rate)));
+    assert_eq!(mine(&GOLD), Err(Ok(shipyard::Error::MiningCooldown)));
+
+    // The last run takes what is left, then the empty deposit fails cleanly
+    game.advance_time(shipyard::MINING_COOLDOWN);
+    assert_eq!(mine(&GOLD), Ok(Ok(50)));
+    assert_eq!(game.star_system.get_resource_amount(&alpha, &GOLD), 0);
+    game.advance_time(shipyard::MINING_COOLDOWN);
+    assert_eq!(mine(&GOLD), Err(Ok(shipyard::Error::DepositEmpty)));
+    assert_eq!(mine(&WATER), Err(Ok(shipyard::Error::DepositEmpty)));
+
+    // Yields stop at what the hold can carry: GOLD weighs 3 a unit, IRON 2
+    game.star_system.add_resource(&game.admin, &alpha, &IRON, &5_000);
+    let mut iron = 0;
+    while 450 + 2 * iron < 1_000 {
+        game.advance_time(shipyard::MINING_COOLDOWN);
+        let mined = mine(&IRON).unwrap().unwrap();
+        assert_eq!(mined, rate.min((1_000 - 450 - 2 * iron) / 2));
+        iron += mined;
+    }
+    assert_eq!(game.shipyard.get_cargo_mass(&ship_id), 1_000);
+    game.advance_time(shipyard::MINING_COOLDOWN);
+    assert_eq!(mine(&IRON), Err(Ok(shipyard::Error::CargoFull)));
+    assert_eq!(
+        game.shipyard.get_ship(&ship_id).resources,
+        map![env, (GOLD, 150), (IRON, 275)]
+    );
+    assert_eq!(game.star_system.get_resource_amount(&alpha, &IRON), 4_725);
+}
+
+#[test]
+fn test_crafted_parts_feed_upgrades_and_repairs() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let plate = Symbol::new(env, "HULL_PLATE");
+    let refinery = symbol_short!("REFINERY");
+
+    game.registry.set_resource(
+        &plate,
+        &ResourceInfo {
+            name: String::from_str(env, "Hull Plate"),
+            rarity: Rarity::Uncommon,
+            unit_mass: 4,
+            tradable: true,
+        },
+    );
+    let recipe = Recipe {
+        inputs: map![env, (IRON, 10), (ENERGY, 5)],
+        output: plate.clone(),
+        output_amount: 1,
+        craft_time: 0,
+        ship_type: None,
+    };
+    game.shipyard.set_recipe(&game.admin, &symbol_short!("PLATE"), &recipe);
+    game.seed_balance(&pilot, &IRON, 25);
+    game.seed_balance(&pilot, &ENERGY, 10);
+
+    // Inputs are spent and the output paid out in one call
+    game.shipyard.craft(&pilot, &symbol_short!("PLATE"), &None);
+    game.shipyard.craft(&pilot, &symbol_short!("PLATE"), &None);
+    assert_eq!(
+        game.economy.get_player_resources(&pilot),
+        map![env, (IRON, 5), (plate.clone(), 2)]
+    );
+
+    // A third craft is short of IRON and ENERGY and changes nothing
+    assert_eq!(
+        game.shipyard.try_craft(&pilot, &symbol_short!("PLATE"), &None),
+        Err(Ok(shipyard::Error::InsufficientFunds))
+    );
+    assert_eq!(game.economy.get_balance(&pilot, &plate), 2);
+    assert_eq!(game.shipyard.get_player_crafts(&pilot).len(), 2);
+
+    // Upgrades take a plate per level, and fail cleanly once plates run out
+    game.shipyard.set_service_parts(
+        &game.admin,
+        &map![env, (plate.clone(), 1)],
+        &map![env, (plate.clone(), 1)],
+    );
+    let ship_id = game.build_ship(&pilot, &symbol_short!("MINER"));
+    game.seed_balance(&pilot, &IRON, 1_000);
+    assert_eq!(
+        game.shipyard.quote_upgrade(&ship_id),
+        map![env, (IRON, 1_000), (plate.clone(), 1)]
+    );
+    game.shipyard.upgrade_ship(&pilot, &ship_id);
+    assert_eq!(game.economy.get_balance(&pilot, &plate), 1);
+    assert_eq!(game.economy.get_balance(&pilot, &IRON), 5);
+    assert_eq!(
+        game.shipyard.try_upgrade_ship(&pilot, &ship_id),
+        Err(Ok(shipyard::Error::InsufficientFunds))
+    );
+    assert_eq!(game.shipyard.get_ship(&ship_id).level, 2);
+
+    // Timed recipes on a refinery hull are claimed once they finish
+    let mut refining = recipe.clone();
+    refining.craft_time = 600;
+    refining.ship_type = Some(refinery.clone());
+    game.shipyard.set_recipe(&game.admin, &symbol_short!("REFINE"), &refining);
+    game.seed_balance(&pilot, &IRON, 5);
+    game.seed_balance(&pilot, &ENERGY, 5);
+    assert_eq!(
+        game.shipyard.try_craft(&pilot, &symbol_short!("REFINE"), &Some(ship_id)),
+        Err(Ok(shipyard::Error::WrongShipType))
+    );
+    let refinery_id = game.build_ship(&pilot, &refinery);
+    let craft_id = game.shipyard.craft(&pilot, &symbol_short!("REFINE"), &Some(refinery_id));
+    assert_eq!(game.economy.get_balance(&pilot, &IRON), 0);
+    assert_eq!(
+        game.shipyard.try_claim_craft(&pilot, &craft_id),
+        Err(Ok(shipyard::Error::CraftNotReady))
+    );
+    game.advance_time(600);
+    assert_eq!(game.shipyard.claim_craft(&pilot, &craft_id), 1);
+    assert_eq!(
+        game.shipyard.try_claim_craft(&pilot, &craft_id),
+        Err(Ok(shipyard::Error::CraftClaimed))
+    );
+    assert_eq!(game.economy.get_balance(&pilot, &plate), 2);
+}
+
+#[test]
+fn test_ships_are_paid_for_and_queued() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let freighter = symbol_short!("FREIGHTER");
+    let order = |ship_type: &Symbol| {
+        game.shipyard.try_order_ship(&pilot, &symbol_short!("HAULER"), ship_type)
+    };
+
+    game.shipyard.set_blueprint(
+        &freighter,
+        &Blueprint {
+            health: 300,
+            cargo_capacity: 4_000,
+            speed: 6,
+            fuel_capacity: 300,
+            mining_rate: 0,
+            module_slots: 5,
+            build_cost: map![env, (IRON, 500), (GOLD, 5)],
+            build_time: 7_200,
+            upgrade: UpgradeCurve {
+                health: 50,
+                cargo_capacity: 1_000,
+                fuel_capacity: 50,
+                mining_rate: 0,
+                max_level: 3,
+            },
+        },
+    );
+    assert_eq!(
+        order(&symbol_short!("DREADNGHT")),
+        Err(Ok(shipyard::Error::UnknownShipType))
+    );
+
+    // The cost is debited when the ship is ordered; orders the pilot cannot
+    // pay for change nothing
+    game.seed_balance(&pilot, &IRON, 1_000);
+    game.seed_balance(&pilot, &GOLD, 7);
+    let build_id = order(&freighter).unwrap().unwrap();
+    assert_eq!(order(&freighter), Err(Ok(shipyard::Error::InsufficientFunds)));
+    assert_eq!(game.economy.get_player_resources(&pilot), map![env, (IRON, 500), (GOLD, 2)]);
+    assert_eq!(game.shipyard.get_player_builds(&pilot).len(), 1);
+
+    // Cancelling refunds half of each cost, rounded down
+    assert_eq!(
+        game.shipyard.try_claim_ship(&pilot, &build_id),
+        Err(Ok(shipyard::Error::BuildNotReady))
+    );
+    assert_eq!(
+        game.shipyard.cancel_build(&pilot, &build_id),
+        map![env, (IRON, 250), (GOLD, 2)]
+    );
+    assert_eq!(game.economy.get_player_resources(&pilot), map![env, (IRON, 750), (GOLD, 4)]);
+    assert_eq!(game.economy.get_total_supply(&IRON), 750);
+
+    // Finished builds are claimed as ships
+    game.seed_balance(&pilot, &GOLD, 1);
+    let build_id = order(&freighter).unwrap().unwrap();
+    game.advance_time(3_600);
+    assert_eq!(game.shipyard.get_build(&build_id).status, BuildStatus::Building);
+    game.advance_time(3_600);
+    assert_eq!(game.shipyard.get_build(&build_id).status, BuildStatus::Ready);
+
+    let ship_id = game.shipyard.claim_ship(&pilot, &build_id);
+    let ship = game.shipyard.get_ship(&ship_id);
+    assert_eq!(ship.type_, freighter);
+    assert_eq!((ship.max_health, ship.cargo_capacity, ship.speed), (300, 4_000, 6));
+    assert_eq!(ship.module_slots, 5);
+    assert_eq!(game.shipyard.get_player_ships(&pilot), vec![env, ship_id]);
+    let build = game.shipyard.get_build(&build_id);
+    assert_eq!(build.status, BuildStatus::Claimed);
+    game.assert_event(
+        &game.shipyard.address,
+        events::BUILD,
+        events::COMPLETED,
+        shipyard::events::BuildChanged { job: build },
+    );
+}
+
+#[test]
+fn test_upgrades_and_repairs_are_charged() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let ship_id = game.build_ship(&pilot, &symbol_short!("MINER"));
+
+    // Quotes match what is charged; a short balance changes nothing
+    assert_eq!(game.shipyard.quote_upgrade(&ship_id), map![env, (IRON, 1_000)]);
+    game.seed_balance(&pilot, &IRON, 999);
+    assert_eq!(
+        game.shipyard.try_upgrade_ship(&pilot, &ship_id),
+        Err(Ok(shipyard::Error::InsufficientFunds))
+    );
+    assert_eq!(game.balance(&pilot, &IRON), 999);
+    assert_eq!(game.shipyard.get_ship(&ship_id).level, 1);
+
+    // The upgrade follows the MINER curve
+    game.seed_balance(&pilot, &IRON, 1);
+    game.shipyard.upgrade_ship(&pilot, &ship_id);
+    let ship = game.shipyard.get_ship(&ship_id);
+    assert_eq!(game.balance(&pilot, &IRON), 0);
+    assert_eq!((ship.level, ship.max_health, ship.health), (2, 144, 144));
+    assert_eq!((ship.cargo_capacity, ship.mining_rate), (1_200, 150));
+    game.assert_event(
+        &game.shipyard.address,
+        events::SHIP,
+        events::UPGRADED,
+        shipyard::events::ShipServiced { ship, charge: map![env, (IRON, 1_000)] },
+    );
+    assert_eq!(game.shipyard.quote_upgrade(&ship_id), map![env, (IRON, 2_828)]);
+
+    // Repairs cost 10 per point of damage
+    let mut damaged = game.shipyard.get_ship(&ship_id);
+    damaged.health -= 44;
+    env.as_contract(&game.shipyard.address, || {
+        env.storage().persistent().set(&shipyard::DataKey::Ship(ship_id), &damaged);
+    });
+    assert_eq!(game.shipyard.quote_repair(&ship_id), map![env, (IRON, 440)]);
+    game.seed_balance(&pilot, &IRON, 500);
+    game.shipyard.repair_ship(&pilot, &ship_id);
+    let ship = game.shipyard.get_ship(&ship_id);
+    assert_eq!(ship.health, 144);
+    assert_eq!(game.balance(&pilot, &IRON), 60);
+    game.assert_event(
+        &game.shipyard.address,
+        events::SHIP,
+        events::REPAIRED,
+        shipyard::events::ShipServiced { ship, charge: map![env, (IRON, 440)] },
+    );
+    assert_eq!(
+        game.shipyard.try_quote_repair(&ship_id),
+        Err(Ok(shipyard::Error::ShipNotDamaged))
+    );
+}
+
+#[test]
+fn test_modules_move_between_balance_and_ship() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let alpha = Symbol::new(env, "ALPHA_CENTAURI");
+    let pod = symbol_short!("CARGO_POD");
+    let laser = symbol_short!("LASER");
+    let big_pod = symbol_short!("BIG_POD");
+
+    // Modules are items in the registry, described to the shipyard
+    for (module, name) in [(&pod, "Cargo Pod"), (&laser, "Mining Laser"), (&big_pod, "Big Pod")] {
+        game.registry.set_resource(
+            module,
+            &ResourceInfo {
+                name: String::from_str(env, name),
+                rarity: Rarity::Uncommon,
+                unit_mass: 10,
+                tradable: true,
+            },
+        );
+    }
+    let pod_spec = Module {
+        slot: SlotType::Cargo,
+        bonus: ShipStats { cargo_capacity: 500, ..Default::default() },
+    };
+    game.shipyard.set_module(&pod, &pod_spec);
+    game.shipyard.set_module(&big_pod, &pod_spec);
+    game.shipyard.set_module(
+        &laser,
+        &Module { slot: SlotType::Mining, bonus: ShipStats { mining_rate: 400, ..Default::default() } },
+    );
+    game.assert_event(
+        &game.shipyard.address,
+        events::MODULE,
+        events::SET,
+        shipyard::events::ModuleSet { module: pod.clone(), spec: pod_spec },
+    );
+
+    // Installing takes the module out of the pilot's balance
+    let ship_id = game.build_ship(&pilot, &symbol_short!("MINER"));
+    assert_eq!(
+        game.shipyard.try_install_module(&pilot, &ship_id, &pod),
+        Err(Ok(shipyard::Error::InsufficientFunds))
+    );
+    game.seed_balance(&pilot, &pod, 1);
+    game.seed_balance(&pilot, &laser, 1);
+    game.seed_balance(&pilot, &big_pod, 1);
+    game.shipyard.install_module(&pilot, &ship_id, &pod);
+    game.shipyard.install_module(&pilot, &ship_id, &laser);
+    assert_eq!(game.economy.get_player_resources(&pilot), map![env, (big_pod.clone(), 1)]);
+    assert_eq!(game.shipyard.get_ship_modules(&ship_id), vec![env, pod.clone(), laser.clone()]);
+    game.assert_event(
+        &game.shipyard.address,
+        events::MODULE,
+        events::INSTALLED,
+        shipyard::events::ShipModuleChanged { ship_id, module: laser.clone() },
+    );
+
+    // One module per slot type, within the ship's slot count
+    assert_eq!(
+        game.shipyard.try_install_module(&pilot, &ship_id, &big_pod),
+        Err(Ok(shipyard::Error::SlotsFull))
+    );
+    game.seed_balance(&pilot, &IRON, 1_000);
+    game.shipyard.upgrade_ship(&pilot, &ship_id);
+    game.seed_balance(&pilot, &IRON, 2_828);
+    game.shipyard.upgrade_ship(&pilot, &ship_id);
+    assert_eq!(game.shipyard.get_module_slots(&ship_id), 3);
+    assert_eq!(
+        game.shipyard.try_install_module(&pilot, &ship_id, &big_pod),
+        Err(Ok(shipyard::Error::SlotTaken))
+    );
+
+    // Mining runs on the effective stats
+    let stats = game.shipyard.get_ship_stats(&ship_id);
+    assert_eq!((stats.cargo_capacity, stats.mining_rate), (1_900, 600));
+    game.star_system.add_resource(&game.admin, &alpha, &IRON, &5_000);
+    assert_eq!(game.shipyard.mine(&pilot, &ship_id, &alpha, &IRON), 600);
+    game.advance_time(shipyard::MINING_COOLDOWN);
+    assert_eq!(game.shipyard.mine(&pilot, &ship_id, &alpha, &IRON), 350);
+
+    // The pod cannot come off while the hold needs it
+    assert_eq!(
+        game.shipyard.try_uninstall_module(&pilot, &ship_id, &pod),
+        Err(Ok(shipyard::Error::CargoOverCapacity))
+    );
+    game.shipyard.uninstall_module(&pilot, &ship_id, &laser);
+    assert_eq!(game.balance(&pilot, &laser), 1);
+    assert_eq!(game.shipyard.get_ship_stats(&ship_id).mining_rate, 200);
+    game.assert_event(
+        &game.shipyard.address,
+        events::MODULE,
+        events::REMOVED,
+        shipyard::events::ShipModuleChanged { ship_id, module: laser },
+    );
+}
+
+#[test]
+fn test_fuel_tank_cannot_come_off_while_in_use() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let tank = symbol_short!("TANK");
+    let barnard = Symbol::new(env, "BARNARDS_STAR");
+
+    game.registry.set_resource(
+        &tank,
+        &ResourceInfo {
+            name: String::from_str(env, "Fuel Tank"),
+            rarity: Rarity::Uncommon,
+            unit_mass: 10,
+            tradable: true,
+        },
+    );
+    game.shipyard.set_module(
+        &tank,
+        &Module { slot: SlotType::Cargo, bonus: ShipStats { fuel_capacity: 100, ..Default::default() } },
+    );
+
+    // Fuel beyond the ship's own tank needs the module to stay fitted
+    let ship_id = game.build_ship(&pilot, &symbol_short!("MINER"));
+    game.seed_balance(&pilot, &tank, 1);
+    game.shipyard.install_module(&pilot, &ship_id, &tank);
+    game.seed_balance(&pilot, &ENERGY, 50);
+    assert_eq!(game.shipyard.refuel(&pilot, &ship_id, &50), 250);
+    assert_eq!(
+        game.shipyard.try_uninstall_module(&pilot, &ship_id, &tank),
+        Err(Ok(shipyard::Error::FuelOverCapacity))
+    );
+    assert_eq!(game.shipyard.get_ship_modules(&ship_id), vec![env, tank.clone()]);
+
+    // Once enough has burned it comes off
+    game.shipyard.travel(&pilot, &ship_id, &barnard);
+    assert_eq!(game.shipyard.get_ship(&ship_id).fuel, 190);
+    game.shipyard.uninstall_module(&pilot, &ship_id, &tank);
+    assert_eq!(game.balance(&pilot, &tank), 1);
+    assert_eq!(game.shipyard.get_ship_stats(&ship_id).fuel_capacity, 200);
+}
+
+#[test]
+fn test_cargo_is_loaded_by_mass() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let rival = game.player();
+    let miner = game.build_ship(&pilot, &symbol_short!("MINER"));
+    let explorer = game.build_ship(&pilot, &symbol_short!("EXPLORER"));
+    let theirs = game.build_ship(&rival, &symbol_short!("EXPLORER"));
+
+    // Loading moves balance into the hold, up to its capacity by mass
+    game.seed_balance(&pilot, &GOLD, 400);
+    game.seed_balance(&pilot, &WATER, 600);
+    game.shipyard.load_cargo(&pilot, &miner, &GOLD, &300);
+    assert_eq!(
+        game.shipyard.try_load_cargo(&pilot, &miner, &WATER, &101),
+        Err(Ok(shipyard::Error::Overloaded))
+    );
+    game.shipyard.load_cargo(&pilot, &miner, &WATER, &100);
+    assert_eq!(game.shipyard.get_cargo_mass(&miner), 1_000);
+    assert_eq!(game.economy.get_player_resources(&pilot), map![env, (GOLD, 100), (WATER, 500)]);
+    game.assert_event(
+        &game.shipyard.address,
+        events::CARGO,
+        events::LOADED,
+        shipyard::events::CargoMoved { ship_id: miner, resource: WATER, amount: 100 },
+    );
+    assert_eq!(
+        game.shipyard.try_load_cargo(&pilot, &explorer, &GOLD, &101),
+        Err(Ok(shipyard::Error::InsufficientFunds))
+    );
+
+    // Transfers stay within one player's ships and respect the receiving hold
+    assert_eq!(
+        game.shipyard.try_transfer_cargo(&pilot, &miner, &theirs, &GOLD, &10),
+        Err(Ok(shipyard::Error::NotShipOwner))
+    );
+    assert_eq!(
+        game.shipyard.try_transfer_cargo(&pilot, &miner, &explorer, &GOLD, &134),
+        Err(Ok(shipyard::Error::Overloaded))
+    );
+    game.shipyard.transfer_cargo(&pilot, &miner, &explorer, &GOLD, &133);
+    assert_eq!(game.shipyard.get_ship(&explorer).resources, map![env, (GOLD, 133)]);
+    assert_eq!(game.shipyard.get_cargo_mass(&miner), 601);
+    game.assert_event(
+        &game.shipyard.address,
+        events::CARGO,
+        events::TRANSFER,
+        shipyard::events::CargoTransferred {
+            from_ship: miner,
+            to_ship: explorer,
+            resource: GOLD,
+            amount: 133,
+        },
+    );
+
+    // Ships at different stars cannot trade cargo
+    let mut away = game.shipyard.get_ship(&explorer);
+    away.location = Symbol::new(env, "BARNARDS_STAR");
+    env.as_contract(&game.shipyard.address, || {
+        env.storage().persistent().set(&shipyard::DataKey::Ship(explorer), &away);
+    });
+    assert_eq!(
+        game.shipyard.try_transfer_cargo(&pilot, &miner, &explorer, &GOLD, &1),
+        Err(Ok(shipyard::Error::ShipsApart))
+    );
+
+    // Unloading returns cargo to the balance
+    assert_eq!(
+        game.shipyard.try_unload_cargo(&pilot, &miner, &WATER, &101),
+        Err(Ok(shipyard::Error::InsufficientCargo))
+    );
+    game.shipyard.unload_cargo(&pilot, &miner, &WATER, &100);
+    assert_eq!(game.shipyard.get_ship(&miner).resources, map![env, (GOLD, 167)]);
+    assert_eq!(game.balance(&pilot, &WATER), 600);
+}
+
+#[test]
+fn test_ships_travel_between_stars() {
+    let game = Harness::new();
+    let env = &game.env;
+    let pilot = game.player();
+    let alpha = Symbol::new(env, "ALPHA_CENTAURI");
+    let proxima = Symbol::new(env, "PROXIMA_CENTAURI");
+    let barnard = Symbol::new(env, "BARNARDS_STAR");
+    let ship_id = game.build_ship(&pilot, &symbol_short!("MINER"));
+    game.star_system.add_resource(&game.admin, &barnard, &IRON, &1_000);
+
+    assert_eq!(
+        game.shipyard.try_travel(&pilot, &ship_id, &symbol_short!("NOWHERE")),
+        Err(Ok(shipyard::Error::StarNotFound))
+    );
+
+    // Fuel burns by distance; speed 8 covers 6 units in 27,000 seconds
+    let departed_at = game.now();
+    assert_eq!(game.shipyard.travel(&pilot, &ship_id, &barnard), departed_at + 27_000);
+    let ship = game.shipyard.get_ship(&ship_id);
+    assert_eq!(ship.fuel, 200 - 60);
+    assert_eq!(ship.location, alpha);
+    game.assert_event(
+        &game.shipyard.address,
+        events::SHIP,
+        events::DEPARTED,
+        shipyard::events::ShipChanged { ship },
+    );
+
+    // Nothing happens aboard until the ship has arrived
+    assert_eq!(
+        game.shipyard.try_travel(&pilot, &ship_id, &proxima),
+        Err(Ok(shipyard::Error::InTransit))
+    );
+    assert_eq!(
+        game.shipyard.try_mine(&pilot, &ship_id, &barnard, &IRON),
+        Err(Ok(shipyard::Error::InTransit))
+    );
+    game.seed_balance(&pilot, &WATER, 10);
+    assert_eq!(
+        game.shipyard.try_load_cargo(&pilot, &ship_id, &WATER, &10),
+        Err(Ok(shipyard::Error::InTransit))
+    );
+    assert_eq!(
+        game.trading.try_create_offer(&pilot, &ship_id, &WATER, &10, &IRON, &1),
+        Err(Ok(trading::Error::ShipInTransit))
+    );
+
+    game.advance_time(6_750);
+    assert_eq!(game.shipyard.get_travel_progress(&ship_id), 25);
+    assert_eq!(game.shipyard.try_arrive(&ship_id), Err(Ok(shipyard::Error::NotArrived)));
+    game.advance_time(20_250);
+    assert_eq!(game.shipyard.get_travel_progress(&ship_id), 100);
+
+    // Arriving docks the ship once; repeating it changes nothing
+    assert_eq!(game.shipyard.arrive(&ship_id), barnard);
+    assert_eq!(game.shipyard.arrive(&ship_id), barnard);
+    let ship = game.shipyard.get_ship(&ship_id);
+    assert_eq!((ship.location.clone(), ship.destination.clone()), (barnard.clone(), None));
+    game.assert_event(
+        &game.shipyard.address,
+        events::SHIP,
+        events::ARRIVED,
+        shipyard::events::ShipChanged { ship },
+    );
+    assert_eq!(game.shipyard.mine(&pilot, &ship_id, &barnard, &IRON), 100);
+
+    // Trips longer than the tank allows need refuelling with ENERGY first
+    game.shipyard.travel(&pilot, &ship_id, &proxima);
+    game.advance_time(27_000);
+    game.shipyard.arrive(&ship_id);
+    game.shipyard.travel(&pilot, &ship_id, &alpha);
+    game.advance_time(18_000);
+    game.shipyard.arrive(&ship_id);
+    assert_eq!(game.shipyard.get_ship(&ship_id).fuel, 40);
+    assert_eq!(
+        game.shipyard.try_travel(&pilot, &ship_id, &barnard),
+        Err(Ok(shipyard::Error::NotEnoughFuel))
+    );
+    assert_eq!(
+        game.shipyard.try_refuel(&pilot, &ship_id, &161),
+        Err(Ok(shipyard::Error::FuelTankFull))
+    );
+    game.seed_balance(&pilot, &ENERGY, 20);
+    assert_eq!(game.shipyard.refuel(&pilot, &ship_id, &20), 60);
+    assert_eq!(game.balance(&pilot, &ENERGY), 0);
+    game.shipyard.travel(&pilot, &ship_id, &barnard);
+    assert_eq!(game.shipyard.get_ship(&ship_id).fuel, 0);
+}
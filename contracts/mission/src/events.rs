@@ -0,0 +1,65 @@
+use game_common::events::{self, ACHIEVE, COMPLETED, CREATED, MISSION};
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{Achievement, Mission};
+
+// (MISSION, CREATED)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissionCreated {
+    pub mission: Mission,
+}
+
+// (MISSION, COMPLETED): `player` completed `mission_id`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissionCompleted {
+    pub player: Address,
+    pub mission_id: u64,
+}
+
+// (ACHIEVE, CREATED)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AchievementCreated {
+    pub achievement: Achievement,
+}
+
+// (ACHIEVE, COMPLETED): `player` earned `achievement_id`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AchievementCompleted {
+    pub player: Address,
+    pub achievement_id: u64,
+}
+
+pub fn mission_created(env: &Env, mission: &Mission) {
+    events::publish(env, MISSION, CREATED, MissionCreated { mission: mission.clone() });
+}
+
+pub fn mission_completed(env: &Env, player: &Address, mission_id: u64) {
+    events::publish(
+        env,
+        MISSION,
+        COMPLETED,
+        MissionCompleted { player: player.clone(), mission_id },
+    );
+}
+
+pub fn achievement_created(env: &Env, achievement: &Achievement) {
+    events::publish(
+        env,
+        ACHIEVE,
+        CREATED,
+        AchievementCreated { achievement: achievement.clone() },
+    );
+}
+
+pub fn achievement_completed(env: &Env, player: &Address, achievement_id: u64) {
+    events::publish(
+        env,
+        ACHIEVE,
+        COMPLETED,
+        AchievementCompleted { player: player.clone(), achievement_id },
+    );
+}
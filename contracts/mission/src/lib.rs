@@ -0,0 +1,323 @@
+#![no_std]
+mod error;
+pub mod events;
+mod migration;
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+
+use game_common::{access, registry, storage, upgrade};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    Address, BytesN, Env, Map, Symbol, Vec,
+};
+
+// Layout version of the records this code writes
+pub const SCHEMA_VERSION: u32 = 3;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    MissionCounter,
+    Mission(u64),
+    PlayerMissions(Address),
+    AchievementCounter,
+    Achievement(u64),
+    PlayerAchievements(Address),
+    Completed(Address, u64), // (player, achievement_id)
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mission {
+    pub id: u64,
+    pub name: Symbol,
+    pub description: Symbol,
+    pub reward: Reward,
+    pub requirements: Requirements,
+    pub completed: bool,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reward {
+    pub resource: Symbol,
+    pub amount: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Requirements {
+    pub stars: u64,
+    pub resources: Map<Symbol, u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Achievement {
+    pub id: u64,
+    pub name: Symbol,
+    pub description: Symbol,
+    pub reward: Reward,
+    pub completed: bool, // no longer set; completion is kept per player
+}
+
+#[contract]
+pub struct MissionContract;
+
+#[contractimpl]
+impl MissionContract {
+    // Admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        access::initialize(&env, &admin)?;
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::transfer_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Ok(access::read_admin(&env)?)
+    }
+
+    pub fn set_operator(env: Env, operator: Address, enabled: bool) -> Result<(), Error> {
+        access::set_operator(&env, &operator, enabled)?;
+        Ok(())
+    }
+
+    pub fn is_operator(env: Env, account: Address) -> bool {
+        access::is_operator(&env, &account)
+    }
+
+    // Upgrades
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, wasm_hash)?;
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    // Registry
+    pub fn set_registry(env: Env, registry: Address) -> Result<(), Error> {
+        registry::set_registry(&env, &registry)?;
+        Ok(())
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, Error> {
+        Ok(registry::read_registry(&env)?)
+    }
+
+    // Rewrite up to `limit` mission records, starting at `start_id`, into the
+    // current layout. Returns the number of missions rewritten.
+    pub fn migrate(env: Env, start_id: u64, limit: u32) -> Result<u32, Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        // Version 3 keeps the id counters in instance storage
+        storage::move_to_instance::<_, u64>(&env, &DataKey::MissionCounter);
+        storage::move_to_instance::<_, u64>(&env, &DataKey::AchievementCounter);
+
+        let last_id = Self::get_mission_counter(&env)
+            .min(start_id.saturating_add(limit as u64).saturating_sub(1));
+        let mut migrated = 0;
+        for id in start_id.max(1)..=last_id {
+            if let Some(mission) = migration::load_mission(&env, id) {
+                storage::write_persistent(&env, &DataKey::Mission(id), &mission);
+                migrated += 1;
+            }
+        }
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(migrated)
+    }
+
+    // Mission Management
+    pub fn create_mission(
+        env: Env,
+        caller: Address,
+        name: Symbol,
+        description: Symbol,
+        reward: Reward,
+        requirements: Requirements,
+    ) -> Result<u64, Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        registry::resource(&env, &reward.resource)?;
+        for resource in requirements.resources.keys() {
+            registry::resource(&env, &resource)?;
+        }
+        let counter = Self::get_mission_counter(&env);
+        let new_counter = counter + 1;
+        
+        let mission = Mission {
+            id: new_counter,
+            name,
+            description,
+            reward,
+            requirements,
+            completed: false,
+            created_at: env.ledger().timestamp(),
+        };
+
+        storage::write_persistent(&env, &DataKey::Mission(new_counter), &mission);
+        env.storage().instance().set(&DataKey::MissionCounter, &new_counter);
+        events::mission_created(&env, &mission);
+
+        Ok(new_counter)
+    }
+
+    pub fn get_mission(env: Env, id: u64) -> Result<Mission, Error> {
+        migration::load_mission(&env, id).ok_or(Error::MissionNotFound)
+    }
+
+    pub fn get_player_missions(env: Env, player: Address) -> Vec<u64> {
+        storage::read_persistent(&env, &DataKey::PlayerMissions(player)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn complete_mission(env: Env, player: Address, mission_id: u64) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let mut mission = Self::get_mission(env.clone(), mission_id)?;
+        
+        if mission.completed {
+            return Err(Error::AlreadyCompleted);
+        }
+
+        Self::check_requirements(&env, &player, &mission.requirements)?;
+
+        mission.completed = true;
+        storage::write_persistent(&env, &DataKey::Mission(mission_id), &mission);
+
+        // Add mission to player's completed missions
+        let mut player_missions = Self::get_player_missions(env.clone(), player.clone());
+        player_missions.push_back(mission_id);
+        storage::write_persistent(&env, &DataKey::PlayerMissions(player.clone()), &player_missions);
+
+        Self::pay_reward(&env, &player, &mission.reward)?;
+        events::mission_completed(&env, &player, mission_id);
+
+        Ok(())
+    }
+
+    // Achievement Management
+    pub fn create_achievement(
+        env: Env,
+        caller: Address,
+        name: Symbol,
+        description: Symbol,
+        reward: Reward,
+    ) -> Result<u64, Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        registry::resource(&env, &reward.resource)?;
+        let counter = Self::get_achievement_counter(&env);
+        let new_counter = counter + 1;
+        
+        let achievement = Achievement {
+            id: new_counter,
+            name,
+            description,
+            reward,
+            completed: false,
+        };
+
+        storage::write_persistent(&env, &DataKey::Achievement(new_counter), &achievement);
+        env.storage().instance().set(&DataKey::AchievementCounter, &new_counter);
+        events::achievement_created(&env, &achievement);
+
+        Ok(new_counter)
+    }
+
+    pub fn get_achievement(env: Env, id: u64) -> Result<Achievement, Error> {
+        storage::read_persistent(&env, &DataKey::Achievement(id)).ok_or(Error::AchievementNotFound)
+    }
+
+    pub fn get_player_achievements(env: Env, player: Address) -> Vec<u64> {
+        storage::read_persistent(&env, &DataKey::PlayerAchievements(player)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Award an achievement to `player`. Achievements carry no requirements
+    // of their own, so only the admin or an operator, which has checked
+    // that the player earned it, may award one. Each player earns it once.
+    pub fn complete_achievement(
+        env: Env,
+        caller: Address,
+        player: Address,
+        achievement_id: u64,
+    ) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        let achievement = Self::get_achievement(env.clone(), achievement_id)?;
+
+        let completed = DataKey::Completed(player.clone(), achievement_id);
+        if env.storage().persistent().has(&completed) {
+            return Err(Error::AlreadyCompleted);
+        }
+        storage::write_persistent(&env, &completed, &true);
+
+        // Add achievement to player's completed achievements
+        let mut player_achievements = Self::get_player_achievements(env.clone(), player.clone());
+        player_achievements.push_back(achievement_id);
+        storage::write_persistent(&env, &DataKey::PlayerAchievements(player.clone()), &player_achievements);
+
+        Self::pay_reward(&env, &player, &achievement.reward)?;
+        events::achievement_completed(&env, &player, achievement_id);
+
+        Ok(())
+    }
+
+    // Helper functions
+    // The player must have discovered `stars` stars and hold the listed
+    // resources in the Economy ledger
+    fn check_requirements(env: &Env, player: &Address, requirements: &Requirements) -> Result<(), Error> {
+        if requirements.stars > 0 {
+            let discovered = registry::star_system(env)?.get_discovery_count(player);
+            if (discovered as u64) < requirements.stars {
+                return Err(Error::RequirementsNotMet);
+            }
+        }
+        if !requirements.resources.is_empty() {
+            let economy = registry::economy(env)?;
+            for (resource, amount) in requirements.resources.iter() {
+                if economy.get_balance(player, &resource) < amount {
+                    return Err(Error::RequirementsNotMet);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Credit the reward to the player's Economy balance. This contract is
+    // an Economy operator.
+    fn pay_reward(env: &Env, player: &Address, reward: &Reward) -> Result<(), Error> {
+        if reward.amount > 0 {
+            registry::economy(env)?.credit(
+                &env.current_contract_address(),
+                player,
+                &reward.resource,
+                &reward.amount,
+            );
+        }
+        Ok(())
+    }
+
+    fn get_mission_counter(env: &Env) -> u64 {
+        storage::read_moved::<_, u64>(env, &DataKey::MissionCounter).unwrap_or(0)
+    }
+
+    fn get_achievement_counter(env: &Env) -> u64 {
+        storage::read_moved::<_, u64>(env, &DataKey::AchievementCounter).unwrap_or(0)
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,42 @@
+use game_common::upgrade;
+use soroban_sdk::{contracttype, Env, Symbol, TryFromVal, Val};
+
+use crate::{DataKey, Mission, Requirements, Reward};
+
+// Mission layout written by schema version 1
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissionV1 {
+    pub id: u64,
+    pub name: Symbol,
+    pub description: Symbol,
+    pub reward: Reward,
+    pub requirements: Requirements,
+    pub completed: bool,
+}
+
+impl From<MissionV1> for Mission {
+    fn from(mission: MissionV1) -> Self {
+        Mission {
+            id: mission.id,
+            name: mission.name,
+            description: mission.description,
+            reward: mission.reward,
+            requirements: mission.requirements,
+            completed: mission.completed,
+            // Creation time was not recorded before version 2
+            created_at: 0,
+        }
+    }
+}
+
+// Read a mission record in whichever layout it was written
+pub fn load_mission(env: &Env, id: u64) -> Option<Mission> {
+    let record: Val = env.storage().persistent().get(&DataKey::Mission(id))?;
+
+    if upgrade::has_field(env, &record, Symbol::new(env, "created_at")) {
+        Some(Mission::try_from_val(env, &record).unwrap())
+    } else {
+        Some(MissionV1::try_from_val(env, &record).unwrap().into())
+    }
+}
@@ -0,0 +1,270 @@
+#![cfg(test)]
+
+use super::*;
+use game_common::registry::Role;
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, Address, Env};
+
+// Stands in for the StarSystem, reporting whatever discovery counts a test
+// sets
+#[contract]
+struct MockStarSystem;
+
+#[contractimpl]
+impl MockStarSystem {
+    pub fn set_discovery_count(env: Env, player: Address, count: u32) {
+        env.storage().instance().set(&player, &count);
+    }
+
+    pub fn get_discovery_count(env: Env, player: Address) -> u32 {
+        env.storage().instance().get(&player).unwrap_or(0)
+    }
+}
+
+// Stands in for the Economy, keeping a plain balance per player and
+// resource
+#[contract]
+struct MockEconomy;
+
+#[contractimpl]
+impl MockEconomy {
+    pub fn get_balance(env: Env, player: Address, resource: Symbol) -> u64 {
+        env.storage().instance().get(&(player, resource)).unwrap_or(0)
+    }
+
+    pub fn credit(env: Env, _caller: Address, player: Address, resource: Symbol, amount: u64) {
+        let balance = Self::get_balance(env.clone(), player.clone(), resource.clone());
+        env.storage().instance().set(&(player, resource), &(balance + amount));
+    }
+}
+
+struct World<'a> {
+    admin: Address,
+    client: MissionContractClient<'a>,
+    star_system: MockStarSystemClient<'a>,
+    economy: MockEconomyClient<'a>,
+}
+
+fn setup(env: &Env) -> World<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MissionContract);
+    let client = MissionContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    // Resource symbols are checked against the registry, which also finds
+    // the contracts requirements are checked against
+    let registry = ::registry::RegistryClient::new(env, &env.register_contract(None, ::registry::Registry));
+    registry.initialize(&admin);
+    client.set_registry(&registry.address);
+    let star_system = MockStarSystemClient::new(env, &env.register_contract(None, MockStarSystem));
+    let economy = MockEconomyClient::new(env, &env.register_contract(None, MockEconomy));
+    registry.set_contract(&Role::StarSystem, &star_system.address);
+    registry.set_contract(&Role::Economy, &economy.address);
+    World { admin, client, star_system, economy }
+}
+
+fn reward() -> Reward {
+    Reward {
+        resource: symbol_short!("GOLD"),
+        amount: 50,
+    }
+}
+
+#[test]
+fn test_complete_mission() {
+    let env = Env::default();
+    let World { admin, client, star_system, economy } = setup(&env);
+    let player = Address::generate(&env);
+    let gold = symbol_short!("GOLD");
+    let water = symbol_short!("WATER");
+    let mut requirements = Requirements {
+        stars: 2,
+        resources: Map::new(&env),
+    };
+    requirements.resources.set(water.clone(), 100);
+
+    let mission_id = client.create_mission(
+        &admin,
+        &symbol_short!("SCOUT"),
+        &symbol_short!("EXPLORE"),
+        &reward(),
+        &requirements,
+    );
+
+    // Requirements are read from the StarSystem and the Economy
+    star_system.set_discovery_count(&player, &1);
+    economy.credit(&admin, &player, &water, &100);
+    assert_eq!(
+        client.try_complete_mission(&player, &mission_id),
+        Err(Ok(Error::RequirementsNotMet))
+    );
+    star_system.set_discovery_count(&player, &2);
+    assert_eq!(economy.get_balance(&player, &gold), 0);
+
+    client.complete_mission(&player, &mission_id);
+    assert_eq!(client.get_player_missions(&player), Vec::from_array(&env, [mission_id]));
+    assert_eq!(economy.get_balance(&player, &gold), 50);
+    assert_eq!(
+        client.try_complete_mission(&player, &mission_id),
+        Err(Ok(Error::AlreadyCompleted))
+    );
+    assert_eq!(client.try_complete_mission(&player, &99), Err(Ok(Error::MissionNotFound)));
+}
+
+#[test]
+fn test_missions_need_held_resources() {
+    let env = Env::default();
+    let World { admin, client, economy, .. } = setup(&env);
+    let player = Address::generate(&env);
+    let mut requirements = Requirements {
+        stars: 0,
+        resources: Map::new(&env),
+    };
+    requirements.resources.set(symbol_short!("IRON"), 300);
+
+    let mission_id = client.create_mission(
+        &admin,
+        &symbol_short!("HAUL"),
+        &symbol_short!("IRON"),
+        &reward(),
+        &requirements,
+    );
+    economy.credit(&admin, &player, &symbol_short!("IRON"), &299);
+    assert_eq!(
+        client.try_complete_mission(&player, &mission_id),
+        Err(Ok(Error::RequirementsNotMet))
+    );
+    economy.credit(&admin, &player, &symbol_short!("IRON"), &1);
+    client.complete_mission(&player, &mission_id);
+    assert!(client.get_mission(&mission_id).completed);
+}
+
+#[test]
+fn test_complete_achievement() {
+    let env = Env::default();
+    let World { admin, client, economy, .. } = setup(&env);
+    let player = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let achievement_id = client.create_achievement(
+        &admin,
+        &symbol_short!("PIONEER"),
+        &symbol_short!("FIRST"),
+        &reward(),
+    );
+
+    // Players cannot award achievements to themselves
+    assert_eq!(
+        client.try_complete_achievement(&player, &player, &achievement_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(economy.get_balance(&player, &symbol_short!("GOLD")), 0);
+    assert_eq!(client.get_player_achievements(&player).len(), 0);
+
+    client.complete_achievement(&admin, &player, &achievement_id);
+    assert_eq!(economy.get_balance(&player, &symbol_short!("GOLD")), 50);
+    assert_eq!(client.get_player_achievements(&player), Vec::from_array(&env, [achievement_id]));
+    assert_eq!(
+        client.try_complete_achievement(&admin, &player, &achievement_id),
+        Err(Ok(Error::AlreadyCompleted))
+    );
+
+    // Earning it does not take it from anyone else
+    client.complete_achievement(&admin, &other, &achievement_id);
+    assert_eq!(economy.get_balance(&other, &symbol_short!("GOLD")), 50);
+    assert_eq!(
+        client.try_get_achievement(&99),
+        Err(Ok(Error::AchievementNotFound))
+    );
+}
+
+#[test]
+fn test_completion_requires_auth() {
+    let env = Env::default();
+    let World { admin, client, .. } = setup(&env);
+    let player = Address::generate(&env);
+    let requirements = Requirements {
+        stars: 1,
+        resources: Map::new(&env),
+    };
+
+    let mission_id = client.create_mission(
+        &admin,
+        &symbol_short!("SCOUT"),
+        &symbol_short!("EXPLORE"),
+        &reward(),
+        &requirements,
+    );
+    let achievement_id = client.create_achievement(
+        &admin,
+        &symbol_short!("PIONEER"),
+        &symbol_short!("FIRST"),
+        &reward(),
+    );
+
+    env.set_auths(&[]);
+    assert!(client.try_complete_mission(&player, &mission_id).is_err());
+    assert!(client.try_complete_achievement(&admin, &player, &achievement_id).is_err());
+    assert!(!client.get_mission(&mission_id).completed);
+}
+
+#[test]
+fn test_create_requires_admin_or_operator() {
+    let env = Env::default();
+    let World { client, .. } = setup(&env);
+    let stranger = Address::generate(&env);
+    let requirements = Requirements {
+        stars: 1,
+        resources: Map::new(&env),
+    };
+
+    assert_eq!(
+        client.try_create_mission(
+            &stranger,
+            &symbol_short!("SCOUT"),
+            &symbol_short!("EXPLORE"),
+            &reward(),
+            &requirements,
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_create_achievement(
+            &stranger,
+            &symbol_short!("PIONEER"),
+            &symbol_short!("FIRST"),
+            &reward(),
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_rewards_need_known_resources() {
+    let env = Env::default();
+    let World { admin, client, .. } = setup(&env);
+    let typo = Reward {
+        resource: symbol_short!("GLOD"),
+        amount: 50,
+    };
+    let mut requirements = Requirements {
+        stars: 0,
+        resources: Map::new(&env),
+    };
+
+    assert_eq!(
+        client.try_create_achievement(&admin, &symbol_short!("ACE"), &symbol_short!("DESC"), &typo),
+        Err(Ok(Error::UnknownResource))
+    );
+    requirements.resources.set(symbol_short!("WATR"), 10);
+    assert_eq!(
+        client.try_create_mission(
+            &admin,
+            &symbol_short!("SURVEY"),
+            &symbol_short!("DESC"),
+            &reward(),
+            &requirements
+        ),
+        Err(Ok(Error::UnknownResource))
+    );
+}
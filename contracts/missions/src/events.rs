@@ -0,0 +1,32 @@
+use game_common::events::{self, COMPLETED, CREATED, MISSION};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::Mission;
+
+// (MISSION, CREATED)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissionCreated {
+    pub mission: Mission,
+}
+
+// (MISSION, COMPLETED): `player` completed `mission_id`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissionCompleted {
+    pub player: Address,
+    pub mission_id: Symbol,
+}
+
+pub fn mission_created(env: &Env, mission: &Mission) {
+    events::publish(env, MISSION, CREATED, MissionCreated { mission: mission.clone() });
+}
+
+pub fn mission_completed(env: &Env, player: &Address, mission_id: &Symbol) {
+    events::publish(
+        env,
+        MISSION,
+        COMPLETED,
+        MissionCompleted { player: player.clone(), mission_id: mission_id.clone() },
+    );
+}
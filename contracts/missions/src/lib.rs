@@ -0,0 +1,265 @@
+#![no_std]
+mod error;
+pub mod events;
+mod migration;
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+
+use game_common::{access, registry, storage, upgrade};
+use game_common::resources::{ENERGY, IRON, WATER};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    Address, BytesN, Env, Map, String, Symbol, Vec,
+};
+
+// Layout version of the records this code writes
+pub const SCHEMA_VERSION: u32 = 3;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Mission(Symbol),
+    PlayerMissions(Address),
+    MissionList,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mission {
+    pub id: Symbol,
+    pub name: String,
+    pub description: String,
+    pub reward_resource: Symbol,
+    pub reward_amount: i128,
+    pub required_stars: Vec<Symbol>,
+    pub required_resources: Map<Symbol, i128>,
+    pub completed: bool,
+    pub created_at: u64,
+}
+
+#[contract]
+pub struct MissionSystem;
+
+#[contractimpl]
+impl MissionSystem {
+    // Admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        access::initialize(&env, &admin)?;
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::transfer_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Ok(access::read_admin(&env)?)
+    }
+
+    pub fn set_operator(env: Env, operator: Address, enabled: bool) -> Result<(), Error> {
+        access::set_operator(&env, &operator, enabled)?;
+        Ok(())
+    }
+
+    pub fn is_operator(env: Env, account: Address) -> bool {
+        access::is_operator(&env, &account)
+    }
+
+    // Upgrades
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, wasm_hash)?;
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    // Registry
+    pub fn set_registry(env: Env, registry: Address) -> Result<(), Error> {
+        registry::set_registry(&env, &registry)?;
+        Ok(())
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, Error> {
+        Ok(registry::read_registry(&env)?)
+    }
+
+    // Rewrite up to `limit` missions from the mission list, starting at
+    // index `start`, into the current layout
+    pub fn migrate(env: Env, start: u32, limit: u32) -> Result<u32, Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        // Version 3 keeps the mission list in instance storage
+        storage::move_to_instance::<_, Vec<Symbol>>(&env, &DataKey::MissionList);
+
+        let mission_list = Self::get_all_missions(env.clone());
+        let end = mission_list.len().min(start.saturating_add(limit));
+        let mut migrated = 0;
+        for index in start..end {
+            let mission_id = mission_list.get_unchecked(index);
+            if let Some(mission) = migration::load_mission(&env, &mission_id) {
+                storage::write_persistent(&env, &DataKey::Mission(mission_id), &mission);
+                migrated += 1;
+            }
+        }
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(migrated)
+    }
+
+    // Initialize mission system
+    pub fn initialize_missions(env: Env, caller: Address) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        // Create initial missions
+        Self::create_mission(
+            &env,
+            Symbol::new(&env, "FIRST_STEPS"),
+            String::from_str(&env, "First Steps"),
+            String::from_str(&env, "Discover your first star system"),
+            ENERGY,
+            100,
+            Vec::new(&env),
+            Map::new(&env)
+        )?;
+
+        Self::create_mission(
+            &env,
+            Symbol::new(&env, "RESOURCE_COLLECTOR"),
+            String::from_str(&env, "Resource Collector"),
+            String::from_str(&env, "Collect 1000 units of resources"),
+            IRON,
+            500,
+            Vec::new(&env),
+            {
+                let mut requirements = Map::new(&env);
+                requirements.set(WATER, 1000);
+                requirements
+            }
+        )?;
+
+        Ok(())
+    }
+
+    // Create a new mission
+    #[allow(clippy::too_many_arguments)]
+    fn create_mission(
+        env: &Env,
+        id: Symbol,
+        name: String,
+        description: String,
+        reward_resource: Symbol,
+        reward_amount: i128,
+        required_stars: Vec<Symbol>,
+        required_resources: Map<Symbol, i128>,
+    ) -> Result<(), Error> {
+        // Check if mission already exists
+        if env.storage().persistent().has(&DataKey::Mission(id.clone())) {
+            return Err(Error::MissionExists);
+        }
+        registry::resource(env, &reward_resource)?;
+        for resource in required_resources.keys() {
+            registry::resource(env, &resource)?;
+        }
+
+        let mission = Mission {
+            id: id.clone(),
+            name,
+            description,
+            reward_resource,
+            reward_amount,
+            required_stars,
+            required_resources,
+            completed: false,
+            created_at: env.ledger().timestamp(),
+        };
+
+        storage::write_persistent(env, &DataKey::Mission(id.clone()), &mission);
+        events::mission_created(env, &mission);
+
+        // Add to mission list
+        let mut mission_list: Vec<Symbol> = storage::read_moved(env, &DataKey::MissionList)
+            .unwrap_or_else(|| Vec::new(env));
+        mission_list.push_back(id);
+        env.storage().instance().set(&DataKey::MissionList, &mission_list);
+
+        Ok(())
+    }
+
+    // Check mission completion against the player's discoveries in the
+    // StarSystem and balances in the Economy ledger
+    pub fn check_mission_completion(
+        env: Env,
+        player: Address,
+        mission_id: Symbol,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        // Get mission
+        let mut mission = migration::load_mission(&env, &mission_id)
+            .ok_or(Error::MissionNotFound)?;
+
+        if mission.completed {
+            return Err(Error::AlreadyCompleted);
+        }
+
+        // Check star requirements
+        if !mission.required_stars.is_empty() {
+            let star_system = registry::star_system(&env)?;
+            for required_star in mission.required_stars.iter() {
+                if !star_system.has_discovered(&player, &required_star) {
+                    return Err(Error::RequirementsNotMet);
+                }
+            }
+        }
+
+        // Check resource requirements
+        if !mission.required_resources.is_empty() {
+            let economy = registry::economy(&env)?;
+            for (resource, amount) in mission.required_resources.iter() {
+                let player_amount = economy.get_balance(&player, &resource);
+                if i128::from(player_amount) < amount {
+                    return Err(Error::RequirementsNotMet);
+                }
+            }
+        }
+
+        // Mark mission as completed
+        mission.completed = true;
+        storage::write_persistent(&env, &DataKey::Mission(mission_id.clone()), &mission);
+
+        // Add to player's completed missions
+        let mut player_missions: Vec<Symbol> =
+            storage::read_persistent(&env, &DataKey::PlayerMissions(player.clone()))
+                .unwrap_or_else(|| Vec::new(&env));
+        player_missions.push_back(mission_id.clone());
+        storage::write_persistent(&env, &DataKey::PlayerMissions(player.clone()), &player_missions);
+        events::mission_completed(&env, &player, &mission_id);
+
+        Ok(())
+    }
+
+    // Get mission details
+    pub fn get_mission_details(env: Env, mission_id: Symbol) -> Result<Mission, Error> {
+        migration::load_mission(&env, &mission_id).ok_or(Error::MissionNotFound)
+    }
+
+    // Get player's completed missions
+    pub fn get_player_missions(env: Env, player: Address) -> Vec<Symbol> {
+        storage::read_persistent(&env, &DataKey::PlayerMissions(player)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Get all available missions
+    pub fn get_all_missions(env: Env) -> Vec<Symbol> {
+        storage::read_moved(&env, &DataKey::MissionList).unwrap_or_else(|| Vec::new(&env))
+    }
+} 
\ No newline at end of file
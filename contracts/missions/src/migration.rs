@@ -0,0 +1,46 @@
+use game_common::upgrade;
+use soroban_sdk::{contracttype, Env, Map, String, Symbol, TryFromVal, Val, Vec};
+
+use crate::{DataKey, Mission};
+
+// Mission layout written by schema version 1
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissionV1 {
+    pub id: Symbol,
+    pub name: String,
+    pub description: String,
+    pub reward_resource: Symbol,
+    pub reward_amount: i128,
+    pub required_stars: Vec<Symbol>,
+    pub required_resources: Map<Symbol, i128>,
+    pub completed: bool,
+}
+
+impl From<MissionV1> for Mission {
+    fn from(mission: MissionV1) -> Self {
+        Mission {
+            id: mission.id,
+            name: mission.name,
+            description: mission.description,
+            reward_resource: mission.reward_resource,
+            reward_amount: mission.reward_amount,
+            required_stars: mission.required_stars,
+            required_resources: mission.required_resources,
+            completed: mission.completed,
+            // Creation time was not recorded before version 2
+            created_at: 0,
+        }
+    }
+}
+
+// Read a mission record in whichever layout it was written
+pub fn load_mission(env: &Env, id: &Symbol) -> Option<Mission> {
+    let record: Val = env.storage().persistent().get(&DataKey::Mission(id.clone()))?;
+
+    if upgrade::has_field(env, &record, Symbol::new(env, "created_at")) {
+        Some(Mission::try_from_val(env, &record).unwrap())
+    } else {
+        Some(MissionV1::try_from_val(env, &record).unwrap().into())
+    }
+}
@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use super::*;
+use game_common::registry::Role;
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, Address, Env};
+
+// Stands in for the StarSystem, recording whichever discoveries a test
+// makes
+#[contract]
+struct MockStarSystem;
+
+#[contractimpl]
+impl MockStarSystem {
+    pub fn discover(env: Env, player: Address, star_name: Symbol) {
+        env.storage().instance().set(&(player, star_name), &true);
+    }
+
+    pub fn has_discovered(env: Env, player: Address, star_name: Symbol) -> bool {
+        env.storage().instance().has(&(player, star_name))
+    }
+}
+
+// Stands in for the Economy, keeping a plain balance per player and
+// resource
+#[contract]
+struct MockEconomy;
+
+#[contractimpl]
+impl MockEconomy {
+    pub fn set_balance(env: Env, player: Address, resource: Symbol, amount: u64) {
+        env.storage().instance().set(&(player, resource), &amount);
+    }
+
+    pub fn get_balance(env: Env, player: Address, resource: Symbol) -> u64 {
+        env.storage().instance().get(&(player, resource)).unwrap_or(0)
+    }
+}
+
+struct World<'a> {
+    admin: Address,
+    client: MissionSystemClient<'a>,
+    star_system: MockStarSystemClient<'a>,
+    economy: MockEconomyClient<'a>,
+}
+
+fn setup(env: &Env) -> World<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, MissionSystem);
+    let client = MissionSystemClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    // Resource symbols are checked against the registry, which also finds
+    // the contracts requirements are checked against
+    let registry = ::registry::RegistryClient::new(env, &env.register_contract(None, ::registry::Registry));
+    registry.initialize(&admin);
+    client.set_registry(&registry.address);
+    let star_system = MockStarSystemClient::new(env, &env.register_contract(None, MockStarSystem));
+    let economy = MockEconomyClient::new(env, &env.register_contract(None, MockEconomy));
+    registry.set_contract(&Role::StarSystem, &star_system.address);
+    registry.set_contract(&Role::Economy, &economy.address);
+    World { admin, client, star_system, economy }
+}
+
+#[test]
+fn test_initialize_missions_twice() {
+    let env = Env::default();
+    let World { admin, client, .. } = setup(&env);
+
+    client.initialize_missions(&admin);
+    assert_eq!(client.get_all_missions().len(), 2);
+    assert_eq!(client.try_initialize_missions(&admin), Err(Ok(Error::MissionExists)));
+}
+
+#[test]
+fn test_check_mission_completion() {
+    let env = Env::default();
+    let World { admin, client, economy, .. } = setup(&env);
+    let player = Address::generate(&env);
+    let mission_id = Symbol::new(&env, "RESOURCE_COLLECTOR");
+
+    client.initialize_missions(&admin);
+
+    economy.set_balance(&player, &WATER, &999);
+    assert_eq!(
+        client.try_check_mission_completion(&player, &mission_id),
+        Err(Ok(Error::RequirementsNotMet))
+    );
+
+    economy.set_balance(&player, &WATER, &1000);
+    client.check_mission_completion(&player, &mission_id);
+    assert_eq!(client.get_player_missions(&player), Vec::from_array(&env, [mission_id.clone()]));
+    assert_eq!(
+        client.try_check_mission_completion(&player, &mission_id),
+        Err(Ok(Error::AlreadyCompleted))
+    );
+}
+
+#[test]
+fn test_missions_need_recorded_discoveries() {
+    let env = Env::default();
+    let World { client, star_system, .. } = setup(&env);
+    let player = Address::generate(&env);
+    let mission_id = symbol_short!("VOYAGE");
+    let barnard = symbol_short!("BARNARD");
+
+    // Missions with star requirements are only created internally
+    env.as_contract(&client.address, || {
+        MissionSystem::create_mission(
+            &env,
+            mission_id.clone(),
+            String::from_str(&env, "Voyage"),
+            String::from_str(&env, "Discover Barnard's Star"),
+            ENERGY,
+            100,
+            Vec::from_array(&env, [barnard.clone()]),
+            Map::new(&env),
+        )
+        .unwrap();
+    });
+    assert_eq!(
+        client.try_check_mission_completion(&player, &mission_id),
+        Err(Ok(Error::RequirementsNotMet))
+    );
+
+    star_system.discover(&player, &barnard);
+    client.check_mission_completion(&player, &mission_id);
+    assert_eq!(client.get_player_missions(&player), Vec::from_array(&env, [mission_id]));
+}
+
+#[test]
+fn test_unknown_mission() {
+    let env = Env::default();
+    let World { client, .. } = setup(&env);
+    let player = Address::generate(&env);
+    let mission_id = symbol_short!("UNKNOWN");
+
+    assert_eq!(
+        client.try_get_mission_details(&mission_id),
+        Err(Ok(Error::MissionNotFound))
+    );
+    assert_eq!(
+        client.try_check_mission_completion(&player, &mission_id),
+        Err(Ok(Error::MissionNotFound))
+    );
+}
+
+#[test]
+fn test_check_mission_completion_requires_auth() {
+    let env = Env::default();
+    let World { admin, client, .. } = setup(&env);
+    let player = Address::generate(&env);
+    let mission_id = Symbol::new(&env, "FIRST_STEPS");
+
+    client.initialize_missions(&admin);
+
+    env.set_auths(&[]);
+    assert!(client
+        .try_check_mission_completion(&player, &mission_id)
+        .is_err());
+    assert_eq!(client.get_player_missions(&player).len(), 0);
+}
+
+#[test]
+fn test_initialize_missions_requires_admin_or_operator() {
+    let env = Env::default();
+    let World { client, .. } = setup(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_initialize_missions(&stranger),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_all_missions().len(), 0);
+}
@@ -0,0 +1,25 @@
+use game_common::access::AccessError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotRegistered = 1,
+    UnknownResource = 2,
+    InvalidResource = 3,
+
+    NotInitialized = 100,
+    AlreadyInitialized = 101,
+    Unauthorized = 102,
+}
+
+impl From<AccessError> for Error {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => Error::NotInitialized,
+            AccessError::AlreadyInitialized => Error::AlreadyInitialized,
+            AccessError::Unauthorized => Error::Unauthorized,
+        }
+    }
+}
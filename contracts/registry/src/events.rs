@@ -0,0 +1,39 @@
+use game_common::events::{self, REGISTRY, REMOVED, RESOURCE, SET};
+use game_common::registry::Role;
+use game_common::resources::ResourceInfo;
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+// (REGISTRY, SET): `role` is now served by `address`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractSet {
+    pub role: Role,
+    pub address: Address,
+}
+
+// (REGISTRY, REMOVED): nothing serves `role` any more
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractRemoved {
+    pub role: Role,
+}
+
+// (RESOURCE, SET): `resource` was added or its metadata replaced
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceSet {
+    pub resource: Symbol,
+    pub info: ResourceInfo,
+}
+
+pub fn contract_set(env: &Env, role: Role, address: &Address) {
+    events::publish(env, REGISTRY, SET, ContractSet { role, address: address.clone() });
+}
+
+pub fn contract_removed(env: &Env, role: Role) {
+    events::publish(env, REGISTRY, REMOVED, ContractRemoved { role });
+}
+
+pub fn resource_set(env: &Env, resource: &Symbol, info: ResourceInfo) {
+    events::publish(env, RESOURCE, SET, ResourceSet { resource: resource.clone(), info });
+}
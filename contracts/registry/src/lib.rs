@@ -0,0 +1,148 @@
+#![no_std]
+mod error;
+pub mod events;
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+
+use game_common::registry::Role;
+use game_common::resources::{self, ResourceInfo};
+use game_common::{access, storage, upgrade};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, Symbol, Vec};
+
+// Layout version of the records this code writes
+pub const SCHEMA_VERSION: u32 = 2;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Contract(Role),
+    Resource(Symbol),
+    ResourceList,
+}
+
+// Maps each part of the game to the address of the contract serving it, and
+// records the resources that exist in the game
+#[contract]
+pub struct Registry;
+
+#[contractimpl]
+impl Registry {
+    // Admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        access::initialize(&env, &admin)?;
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+        storage::extend_instance(&env);
+
+        Self::seed_resources(&env);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::transfer_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Ok(access::read_admin(&env)?)
+    }
+
+    // Upgrades
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, wasm_hash)?;
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    // Version 2 records resource metadata; registries deployed before it
+    // start out knowing the base resources
+    pub fn migrate(env: Env) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        Self::seed_resources(&env);
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(())
+    }
+
+    // Registry
+    pub fn set_contract(env: Env, role: Role, address: Address) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        env.storage().instance().set(&DataKey::Contract(role.clone()), &address);
+        events::contract_set(&env, role, &address);
+
+        Ok(())
+    }
+
+    pub fn remove_contract(env: Env, role: Role) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        if !env.storage().instance().has(&DataKey::Contract(role.clone())) {
+            return Err(Error::NotRegistered);
+        }
+        env.storage().instance().remove(&DataKey::Contract(role.clone()));
+        events::contract_removed(&env, role);
+
+        Ok(())
+    }
+
+    pub fn get_contract(env: Env, role: Role) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Contract(role))
+            .ok_or(Error::NotRegistered)
+    }
+
+    // Resources
+    pub fn set_resource(env: Env, resource: Symbol, info: ResourceInfo) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        if info.unit_mass == 0 {
+            return Err(Error::InvalidResource);
+        }
+        Self::write_resource(&env, &resource, &info);
+        events::resource_set(&env, &resource, info);
+
+        Ok(())
+    }
+
+    pub fn get_resource(env: Env, resource: Symbol) -> Result<ResourceInfo, Error> {
+        storage::read_persistent(&env, &DataKey::Resource(resource)).ok_or(Error::UnknownResource)
+    }
+
+    // Every resource symbol the registry knows, in the order they were added
+    pub fn get_resources(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ResourceList)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    fn seed_resources(env: &Env) {
+        for (resource, info) in resources::base_resources(env).iter() {
+            if !env.storage().persistent().has(&DataKey::Resource(resource.clone())) {
+                Self::write_resource(env, &resource, &info);
+                events::resource_set(env, &resource, info);
+            }
+        }
+    }
+
+    fn write_resource(env: &Env, resource: &Symbol, info: &ResourceInfo) {
+        let key = DataKey::Resource(resource.clone());
+        if !env.storage().persistent().has(&key) {
+            let mut list = Self::get_resources(env.clone());
+            list.push_back(resource.clone());
+            env.storage().instance().set(&DataKey::ResourceList, &list);
+        }
+        storage::write_persistent(env, &key, info);
+    }
+}
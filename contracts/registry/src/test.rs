@@ -0,0 +1,198 @@
+#![cfg(test)]
+
+use super::*;
+use game_common::events::{
+    AdminChanged, VersionSet, ADMIN, CHANGED, CONTRACT, EVENT_VERSION, REGISTRY, REMOVED,
+    RESOURCE, SET, VERSION,
+};
+use game_common::resources::{Rarity, ENERGY, GOLD, IRON, WATER};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Env, IntoVal, String,
+};
+
+fn setup(env: &Env) -> (Address, RegistryClient<'_>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, Registry);
+    let client = RegistryClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (admin, client)
+}
+
+#[test]
+fn test_register_and_replace_contracts() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let economy = Address::generate(&env);
+    let replacement = Address::generate(&env);
+
+    assert_eq!(
+        client.try_get_contract(&Role::Economy),
+        Err(Ok(Error::NotRegistered))
+    );
+
+    client.set_contract(&Role::Economy, &economy);
+    assert_eq!(client.get_contract(&Role::Economy), economy);
+
+    client.set_contract(&Role::Economy, &replacement);
+    assert_eq!(client.get_contract(&Role::Economy), replacement);
+    assert_eq!(
+        client.try_get_contract(&Role::Shipyard),
+        Err(Ok(Error::NotRegistered))
+    );
+
+    client.remove_contract(&Role::Economy);
+    assert_eq!(
+        client.try_remove_contract(&Role::Economy),
+        Err(Ok(Error::NotRegistered))
+    );
+}
+
+#[test]
+fn test_updates_require_admin() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let economy = Address::generate(&env);
+
+    env.set_auths(&[]);
+    assert!(client.try_set_contract(&Role::Economy, &economy).is_err());
+    assert!(client.try_remove_contract(&Role::Economy).is_err());
+    env.mock_all_auths();
+
+    client.set_contract(&Role::Economy, &economy);
+    assert_eq!(client.get_contract(&Role::Economy), economy);
+}
+
+#[test]
+fn test_registry_events() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let economy = Address::generate(&env);
+    let topics = |namespace, action| (namespace, action, EVENT_VERSION).into_val(&env);
+
+    let mut expected = vec![
+        &env,
+        (
+            client.address.clone(),
+            topics(ADMIN, CHANGED),
+            AdminChanged { admin }.into_val(&env),
+        ),
+        (
+            client.address.clone(),
+            topics(CONTRACT, VERSION),
+            VersionSet { version: SCHEMA_VERSION }.into_val(&env),
+        ),
+    ];
+    // Seeded resources are published like any other
+    for (resource, info) in resources::base_resources(&env).iter() {
+        expected.push_back((
+            client.address.clone(),
+            topics(RESOURCE, SET),
+            events::ResourceSet { resource, info }.into_val(&env),
+        ));
+    }
+    assert_eq!(env.events().all(), expected);
+
+    client.set_contract(&Role::Economy, &economy);
+    client.remove_contract(&Role::Economy);
+    assert_eq!(
+        env.events().all().slice(6..),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                topics(REGISTRY, SET),
+                events::ContractSet { role: Role::Economy, address: economy }.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                topics(REGISTRY, REMOVED),
+                events::ContractRemoved { role: Role::Economy }.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_migrate_publishes_seeded_resources() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    // Simulate a registry from before IRON was a base resource
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&DataKey::Resource(IRON));
+        env.storage()
+            .instance()
+            .set(&DataKey::ResourceList, &vec![&env, WATER, ENERGY, GOLD]);
+    });
+
+    client.migrate();
+    assert_eq!(client.get_resources(), vec![&env, WATER, ENERGY, GOLD, IRON]);
+    let iron = resources::base_resources(&env).get_unchecked(0).1;
+    let all = env.events().all();
+    assert_eq!(
+        all.slice(all.len() - 2..),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (RESOURCE, SET, EVENT_VERSION).into_val(&env),
+                events::ResourceSet { resource: IRON, info: iron }.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (CONTRACT, VERSION, EVENT_VERSION).into_val(&env),
+                VersionSet { version: SCHEMA_VERSION }.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_resource_metadata() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let crystal = symbol_short!("CRYSTAL");
+
+    assert_eq!(client.get_resources(), vec![&env, IRON, WATER, ENERGY, GOLD]);
+    let iron = client.get_resource(&IRON);
+    assert_eq!(iron.name, String::from_str(&env, "Iron"));
+    assert_eq!(iron.rarity, Rarity::Common);
+    assert!(iron.tradable);
+    assert_eq!(client.try_get_resource(&crystal), Err(Ok(Error::UnknownResource)));
+
+    let info = ResourceInfo {
+        name: String::from_str(&env, "Void Crystal"),
+        rarity: Rarity::Exotic,
+        unit_mass: 5,
+        tradable: false,
+    };
+    client.set_resource(&crystal, &info);
+    assert_eq!(client.get_resource(&crystal), info);
+    let all = env.events().all();
+    assert_eq!(
+        all.slice(all.len() - 1..),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (RESOURCE, SET, EVENT_VERSION).into_val(&env),
+                events::ResourceSet { resource: crystal.clone(), info: info.clone() }.into_val(&env),
+            ),
+        ]
+    );
+
+    // Replacing metadata keeps the resource's place in the list
+    client.set_resource(&crystal, &ResourceInfo { tradable: true, ..info.clone() });
+    assert_eq!(client.get_resources().len(), 5);
+    assert!(client.get_resource(&crystal).tradable);
+
+    assert_eq!(
+        client.try_set_resource(&crystal, &ResourceInfo { unit_mass: 0, ..info.clone() }),
+        Err(Ok(Error::InvalidResource))
+    );
+    env.set_auths(&[]);
+    assert!(client.try_set_resource(&crystal, &info).is_err());
+}
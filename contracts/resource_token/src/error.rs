@@ -0,0 +1,29 @@
+use game_common::access::AccessError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotMinter = 1,
+    NegativeAmount = 2,
+    InsufficientBalance = 3,
+    InsufficientAllowance = 4,
+    InvalidExpiration = 5,
+
+    NotInitialized = 100,
+    AlreadyInitialized = 101,
+    Unauthorized = 102,
+
+    Overflow = 110,
+}
+
+impl From<AccessError> for Error {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => Error::NotInitialized,
+            AccessError::AlreadyInitialized => Error::AlreadyInitialized,
+            AccessError::Unauthorized => Error::Unauthorized,
+        }
+    }
+}
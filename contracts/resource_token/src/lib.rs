@@ -0,0 +1,315 @@
+#![no_std]
+mod error;
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+
+use game_common::{access, storage, upgrade};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, symbol_short, token::TokenInterface,
+    Address, BytesN, Env, String,
+};
+
+// Layout version of the records this code writes
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Metadata,
+    Minter(Address),
+    Balance(Address),
+    Allowance(Address, Address), // (from, spender)
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Metadata {
+    pub decimals: u32,
+    pub name: String,
+    pub symbol: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Allowance {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+// A game resource issued as a SEP-41 token. Holders use the standard token
+// interface; the Economy, registered as minter, issues tokens for ledger
+// balances players withdraw and consumes the ones they deposit back.
+// Token operations publish the standard SEP-41 events rather than the game
+// event schema, so wallets and explorers pick them up.
+#[contract]
+pub struct ResourceToken;
+
+#[contractimpl]
+impl ResourceToken {
+    // Admin
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        decimals: u32,
+        name: String,
+        symbol: String,
+    ) -> Result<(), Error> {
+        access::initialize(&env, &admin)?;
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+        env.storage()
+            .instance()
+            .set(&DataKey::Metadata, &Metadata { decimals, name, symbol });
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::transfer_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Ok(access::read_admin(&env)?)
+    }
+
+    // Allow or stop `minter` minting and consuming this resource
+    pub fn set_minter(env: Env, minter: Address, enabled: bool) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        let key = DataKey::Minter(minter.clone());
+        if enabled {
+            storage::write_persistent(&env, &key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events()
+            .publish((symbol_short!("minter"), minter), enabled);
+
+        Ok(())
+    }
+
+    pub fn is_minter(env: Env, account: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Minter(account))
+    }
+
+    // Upgrades
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, wasm_hash)?;
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    // Supply
+    pub fn mint(env: Env, minter: Address, to: Address, amount: i128) -> Result<(), Error> {
+        Self::require_minter(&env, &minter)?;
+        check_nonnegative(amount)?;
+        storage::extend_instance(&env);
+
+        credit(&env, &to, amount)?;
+        env.events()
+            .publish((symbol_short!("mint"), minter, to), amount);
+
+        Ok(())
+    }
+
+    // Burn `amount` from `from` on the game's behalf. Minters only, and
+    // `from` must have signed too.
+    pub fn consume(env: Env, minter: Address, from: Address, amount: i128) -> Result<(), Error> {
+        Self::require_minter(&env, &minter)?;
+        from.require_auth();
+        check_nonnegative(amount)?;
+        storage::extend_instance(&env);
+
+        debit(&env, &from, amount)?;
+        env.events().publish((symbol_short!("burn"), from), amount);
+
+        Ok(())
+    }
+
+    fn require_minter(env: &Env, minter: &Address) -> Result<(), Error> {
+        minter.require_auth();
+        if !Self::is_minter(env.clone(), minter.clone()) {
+            return Err(Error::NotMinter);
+        }
+        Ok(())
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for ResourceToken {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        read_allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        unwrap_or_panic(&env, check_nonnegative(amount));
+        storage::extend_instance(&env);
+
+        unwrap_or_panic(&env, write_allowance(&env, &from, &spender, amount, expiration_ledger));
+        env.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (amount, expiration_ledger),
+        );
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        read_balance(&env, &id)
+    }
+
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        unwrap_or_panic(&env, check_nonnegative(amount));
+        storage::extend_instance(&env);
+
+        unwrap_or_panic(&env, move_balance(&env, &from, &to, amount));
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        unwrap_or_panic(&env, check_nonnegative(amount));
+        storage::extend_instance(&env);
+
+        unwrap_or_panic(&env, spend_allowance(&env, &from, &spender, amount));
+        unwrap_or_panic(&env, move_balance(&env, &from, &to, amount));
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        unwrap_or_panic(&env, check_nonnegative(amount));
+        storage::extend_instance(&env);
+
+        unwrap_or_panic(&env, debit(&env, &from, amount));
+        env.events().publish((symbol_short!("burn"), from), amount);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        unwrap_or_panic(&env, check_nonnegative(amount));
+        storage::extend_instance(&env);
+
+        unwrap_or_panic(&env, spend_allowance(&env, &from, &spender, amount));
+        unwrap_or_panic(&env, debit(&env, &from, amount));
+        env.events().publish((symbol_short!("burn"), from), amount);
+    }
+
+    fn decimals(env: Env) -> u32 {
+        read_metadata(&env).decimals
+    }
+
+    fn name(env: Env) -> String {
+        read_metadata(&env).name
+    }
+
+    fn symbol(env: Env) -> String {
+        read_metadata(&env).symbol
+    }
+}
+
+// The SEP-41 entry points have no error channel, so failures abort with
+// the contract error instead
+fn unwrap_or_panic<T>(env: &Env, result: Result<T, Error>) -> T {
+    result.unwrap_or_else(|error| panic_with_error!(env, error))
+}
+
+fn check_nonnegative(amount: i128) -> Result<(), Error> {
+    if amount < 0 {
+        return Err(Error::NegativeAmount);
+    }
+    Ok(())
+}
+
+fn read_metadata(env: &Env) -> Metadata {
+    env.storage()
+        .instance()
+        .get(&DataKey::Metadata)
+        .unwrap_or_else(|| panic_with_error!(env, Error::NotInitialized))
+}
+
+fn read_balance(env: &Env, id: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Balance(id.clone()))
+        .unwrap_or(0)
+}
+
+fn credit(env: &Env, id: &Address, amount: i128) -> Result<(), Error> {
+    let balance = read_balance(env, id)
+        .checked_add(amount)
+        .ok_or(Error::Overflow)?;
+    storage::write_persistent(env, &DataKey::Balance(id.clone()), &balance);
+    Ok(())
+}
+
+fn debit(env: &Env, id: &Address, amount: i128) -> Result<(), Error> {
+    let balance = read_balance(env, id);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    storage::write_persistent(env, &DataKey::Balance(id.clone()), &(balance - amount));
+    Ok(())
+}
+
+fn move_balance(env: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+    debit(env, from, amount)?;
+    credit(env, to, amount)
+}
+
+// Expired allowances read as zero
+fn read_allowance(env: &Env, from: &Address, spender: &Address) -> Allowance {
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    match env.storage().temporary().get::<_, Allowance>(&key) {
+        Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => allowance,
+        _ => Allowance { amount: 0, expiration_ledger: 0 },
+    }
+}
+
+fn write_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) -> Result<(), Error> {
+    let current = env.ledger().sequence();
+    if amount > 0 && expiration_ledger < current {
+        return Err(Error::InvalidExpiration);
+    }
+
+    let key = DataKey::Allowance(from.clone(), spender.clone());
+    let allowance = Allowance { amount, expiration_ledger };
+    if amount > 0 {
+        // Keep the entry alive exactly until it expires
+        storage::write_temporary(env, &key, &allowance, expiration_ledger - current);
+    } else {
+        env.storage().temporary().set(&key, &allowance);
+    }
+    Ok(())
+}
+
+fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) -> Result<(), Error> {
+    let allowance = read_allowance(env, from, spender);
+    if allowance.amount < amount {
+        return Err(Error::InsufficientAllowance);
+    }
+    if amount > 0 {
+        write_allowance(
+            env,
+            from,
+            spender,
+            allowance.amount - amount,
+            allowance.expiration_ledger,
+        )?;
+    }
+    Ok(())
+}
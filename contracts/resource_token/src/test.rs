@@ -0,0 +1,204 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    token::TokenClient,
+    vec, Address, Env, IntoVal,
+};
+
+fn setup(env: &Env) -> (Address, ResourceTokenClient<'_>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ResourceToken);
+    let client = ResourceTokenClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(
+        &admin,
+        &0,
+        &String::from_str(env, "Iron"),
+        &String::from_str(env, "IRON"),
+    );
+    (admin, client)
+}
+
+fn minter(env: &Env, client: &ResourceTokenClient) -> Address {
+    let minter = Address::generate(env);
+    client.set_minter(&minter, &true);
+    minter
+}
+
+#[test]
+fn test_metadata() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let token = TokenClient::new(&env, &client.address);
+
+    assert_eq!(token.decimals(), 0);
+    assert_eq!(token.name(), String::from_str(&env, "Iron"));
+    assert_eq!(token.symbol(), String::from_str(&env, "IRON"));
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_initialize(
+            &admin,
+            &0,
+            &String::from_str(&env, "Iron"),
+            &String::from_str(&env, "IRON"),
+        ),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_only_minters_create_and_consume_supply() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let game = minter(&env, &client);
+    let player = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.mint(&game, &player, &500);
+    assert_eq!(client.balance(&player), 500);
+    assert_eq!(
+        env.events().all().slice(env.events().all().len() - 1..),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("mint"), game.clone(), player.clone()).into_val(&env),
+                500_i128.into_val(&env),
+            ),
+        ]
+    );
+
+    client.consume(&game, &player, &200);
+    assert_eq!(client.balance(&player), 300);
+    assert_eq!(
+        client.try_consume(&game, &player, &301),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_mint(&game, &player, &-1),
+        Err(Ok(Error::NegativeAmount))
+    );
+
+    assert_eq!(
+        client.try_mint(&stranger, &player, &1),
+        Err(Ok(Error::NotMinter))
+    );
+    client.set_minter(&game, &false);
+    assert!(!client.is_minter(&game));
+    assert_eq!(
+        client.try_consume(&game, &player, &1),
+        Err(Ok(Error::NotMinter))
+    );
+
+    env.set_auths(&[]);
+    assert!(client.try_set_minter(&stranger, &true).is_err());
+}
+
+#[test]
+fn test_minter_changes_are_published() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let game = minter(&env, &client);
+
+    client.set_minter(&game, &false);
+    assert_eq!(
+        env.events().all().slice(env.events().all().len() - 1..),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("minter"), game.clone()).into_val(&env),
+                false.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_consume_needs_the_holder_to_sign() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let game = minter(&env, &client);
+    let player = Address::generate(&env);
+    client.mint(&game, &player, &100);
+
+    // Only the minter signs
+    env.mock_auths(&[MockAuth {
+        address: &game,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "consume",
+            args: (game.clone(), player.clone(), 40_i128).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_consume(&game, &player, &40).is_err());
+
+    env.mock_all_auths();
+    client.consume(&game, &player, &40);
+    assert_eq!(client.balance(&player), 60);
+}
+
+#[test]
+fn test_transfer_and_burn() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let game = minter(&env, &client);
+    let token = TokenClient::new(&env, &client.address);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.mint(&game, &alice, &100);
+
+    token.transfer(&alice, &bob, &40);
+    assert_eq!(token.balance(&alice), 60);
+    assert_eq!(token.balance(&bob), 40);
+    assert_eq!(
+        env.events().all().slice(env.events().all().len() - 1..),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), alice.clone(), bob.clone()).into_val(&env),
+                40_i128.into_val(&env),
+            ),
+        ]
+    );
+    assert!(token.try_transfer(&alice, &bob, &61).is_err());
+
+    token.burn(&bob, &15);
+    assert_eq!(token.balance(&bob), 25);
+    assert!(token.try_burn(&bob, &26).is_err());
+}
+
+#[test]
+fn test_allowances() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let game = minter(&env, &client);
+    let token = TokenClient::new(&env, &client.address);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.mint(&game, &owner, &100);
+
+    token.approve(&owner, &spender, &50, &100);
+    assert_eq!(token.allowance(&owner, &spender), 50);
+
+    token.transfer_from(&spender, &owner, &receiver, &30);
+    assert_eq!(token.allowance(&owner, &spender), 20);
+    assert_eq!(token.balance(&receiver), 30);
+    assert!(token.try_transfer_from(&spender, &owner, &receiver, &21).is_err());
+
+    token.burn_from(&spender, &owner, &20);
+    assert_eq!(token.allowance(&owner, &spender), 0);
+    assert_eq!(token.balance(&owner), 50);
+
+    // Allowances lapse after their expiration ledger
+    token.approve(&owner, &spender, &10, &100);
+    env.ledger().with_mut(|ledger| ledger.sequence_number = 101);
+    assert_eq!(token.allowance(&owner, &spender), 0);
+    assert!(token.try_transfer_from(&spender, &owner, &receiver, &1).is_err());
+    assert!(token.try_approve(&owner, &spender, &10, &100).is_err());
+}
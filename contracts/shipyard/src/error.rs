@@ -0,0 +1,93 @@
+use game_common::access::AccessError;
+use game_common::math::MathError;
+use game_common::registry::RegistryError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    ShipNotFound = 1,
+    NotShipOwner = 2,
+    ShipNotDamaged = 3,
+    ShipNotAtStar = 4,
+    MiningCooldown = 5,
+    CargoFull = 6,
+    DepositEmpty = 7,
+    RecipeNotFound = 8,
+    InvalidRecipe = 9,
+    WrongShipType = 10,
+    CraftNotFound = 11,
+    NotCraftOwner = 12,
+    CraftNotReady = 13,
+    CraftClaimed = 14,
+    UnknownShipType = 15,
+    BuildNotFound = 16,
+    NotBuildOwner = 17,
+    BuildNotReady = 18,
+    BuildClosed = 19,
+    InvalidBlueprint = 20,
+    MaxLevel = 21,
+    UnknownModule = 22,
+    SlotsFull = 23,
+    SlotTaken = 24,
+    ModuleNotInstalled = 25,
+    CargoOverCapacity = 26,
+    Overloaded = 27,
+    InsufficientCargo = 28,
+    ShipsApart = 29,
+    SameShip = 30,
+    InvalidAmount = 31,
+    InTransit = 32,
+    NotArrived = 33,
+    NotEnoughFuel = 34,
+    FuelTankFull = 35,
+    AlreadyAtStar = 36,
+    FuelOverCapacity = 37,
+    InsufficientFunds = 38,
+    StarNotFound = 39,
+
+    NotInitialized = 100,
+    AlreadyInitialized = 101,
+    Unauthorized = 102,
+
+    Overflow = 110,
+    DivisionByZero = 111,
+    OutOfDomain = 112,
+
+    RegistryNotSet = 120,
+    NotRegistered = 121,
+    UnknownResource = 122,
+    NotTradable = 123,
+}
+
+impl From<AccessError> for Error {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => Error::NotInitialized,
+            AccessError::AlreadyInitialized => Error::AlreadyInitialized,
+            AccessError::Unauthorized => Error::Unauthorized,
+        }
+    }
+}
+
+impl From<MathError> for Error {
+    fn from(error: MathError) -> Self {
+        match error {
+            MathError::Overflow => Error::Overflow,
+            MathError::DivisionByZero => Error::DivisionByZero,
+            MathError::OutOfDomain => Error::OutOfDomain,
+        }
+    }
+}
+
+impl From<RegistryError> for Error {
+    fn from(error: RegistryError) -> Self {
+        match error {
+            RegistryError::RegistryNotSet => Error::RegistryNotSet,
+            RegistryError::NotRegistered => Error::NotRegistered,
+            RegistryError::UnknownResource => Error::UnknownResource,
+            RegistryError::NotTradable => Error::NotTradable,
+        }
+    }
+}
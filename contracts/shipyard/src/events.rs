@@ -0,0 +1,221 @@
+use game_common::events::{
+    self, ARRIVED, DEPARTED, REFUELED, BUILD, CANCELLED, CARGO, COLLECTED, COMPLETED, CRAFT, CREATED, INSTALLED, LOADED, MODULE, RECIPE, REMOVED,
+    REPAIRED, RESOURCE, SET, SHIP, TRANSFER, UNLOADED, UPGRADED,
+};
+use soroban_sdk::{contracttype, Env, Map, Symbol};
+
+use crate::{Blueprint, BuildJob, CraftJob, Module, Recipe, Ship};
+
+// (SHIP, CREATED), (SHIP, REFUELED), (SHIP, DEPARTED) and (SHIP, ARRIVED):
+// the ship record as it now stands
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShipChanged {
+    pub ship: Ship,
+}
+
+// (SHIP, UPGRADED) and (SHIP, REPAIRED): the ship as it now stands and what
+// the owner was charged for it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShipServiced {
+    pub ship: Ship,
+    pub charge: Map<Symbol, u64>,
+}
+
+// (RESOURCE, COLLECTED): ship `ship_id` mined `amount` of `resource` at
+// `star` into its cargo
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceMined {
+    pub ship_id: u64,
+    pub star: Symbol,
+    pub resource: Symbol,
+    pub amount: u64,
+}
+
+// (RECIPE, SET): `recipe_id` was added or replaced
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecipeSet {
+    pub recipe_id: Symbol,
+    pub recipe: Recipe,
+}
+
+// (CRAFT, CREATED) and (CRAFT, COMPLETED): the craft as it now stands
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CraftChanged {
+    pub job: CraftJob,
+}
+
+// (SHIP, SET): parts consumed by upgrades and repairs
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ServicePartsSet {
+    pub upgrade_parts: Map<Symbol, u64>,
+    pub repair_parts: Map<Symbol, u64>,
+}
+
+// (BUILD, SET): the blueprint ships of `ship_type` are now built from
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlueprintSet {
+    pub ship_type: Symbol,
+    pub blueprint: Blueprint,
+}
+
+// (BUILD, CREATED), (BUILD, CANCELLED) and (BUILD, COMPLETED): the build as
+// it now stands
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildChanged {
+    pub job: BuildJob,
+}
+
+// (MODULE, SET): what `module` now does when fitted
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModuleSet {
+    pub module: Symbol,
+    pub spec: Module,
+}
+
+// (MODULE, INSTALLED) and (MODULE, REMOVED): one `module` was fitted to or
+// taken off ship `ship_id`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShipModuleChanged {
+    pub ship_id: u64,
+    pub module: Symbol,
+}
+
+// (CARGO, LOADED) and (CARGO, UNLOADED): `amount` of `resource` moved
+// between ship `ship_id`'s hold and its owner's balance
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CargoMoved {
+    pub ship_id: u64,
+    pub resource: Symbol,
+    pub amount: u64,
+}
+
+// (CARGO, TRANSFER): `amount` of `resource` moved from one ship's hold to
+// another's
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CargoTransferred {
+    pub from_ship: u64,
+    pub to_ship: u64,
+    pub resource: Symbol,
+    pub amount: u64,
+}
+
+pub fn ship_created(env: &Env, ship: &Ship) {
+    events::publish(env, SHIP, CREATED, ShipChanged { ship: ship.clone() });
+}
+
+pub fn ship_refueled(env: &Env, ship: &Ship) {
+    events::publish(env, SHIP, REFUELED, ShipChanged { ship: ship.clone() });
+}
+
+pub fn ship_departed(env: &Env, ship: &Ship) {
+    events::publish(env, SHIP, DEPARTED, ShipChanged { ship: ship.clone() });
+}
+
+pub fn ship_arrived(env: &Env, ship: &Ship) {
+    events::publish(env, SHIP, ARRIVED, ShipChanged { ship: ship.clone() });
+}
+
+pub fn ship_upgraded(env: &Env, ship: &Ship, charge: &Map<Symbol, u64>) {
+    events::publish(env, SHIP, UPGRADED, ShipServiced { ship: ship.clone(), charge: charge.clone() });
+}
+
+pub fn ship_repaired(env: &Env, ship: &Ship, charge: &Map<Symbol, u64>) {
+    events::publish(env, SHIP, REPAIRED, ShipServiced { ship: ship.clone(), charge: charge.clone() });
+}
+
+pub fn resource_mined(env: &Env, ship_id: u64, star: &Symbol, resource: &Symbol, amount: u64) {
+    events::publish(
+        env,
+        RESOURCE,
+        COLLECTED,
+        ResourceMined { ship_id, star: star.clone(), resource: resource.clone(), amount },
+    );
+}
+
+pub fn recipe_set(env: &Env, recipe_id: &Symbol, recipe: &Recipe) {
+    events::publish(
+        env,
+        RECIPE,
+        SET,
+        RecipeSet { recipe_id: recipe_id.clone(), recipe: recipe.clone() },
+    );
+}
+
+pub fn craft_started(env: &Env, job: &CraftJob) {
+    events::publish(env, CRAFT, CREATED, CraftChanged { job: job.clone() });
+}
+
+pub fn craft_completed(env: &Env, job: &CraftJob) {
+    events::publish(env, CRAFT, COMPLETED, CraftChanged { job: job.clone() });
+}
+
+pub fn service_parts_set(env: &Env, upgrade_parts: &Map<Symbol, u64>, repair_parts: &Map<Symbol, u64>) {
+    events::publish(
+        env,
+        SHIP,
+        SET,
+        ServicePartsSet { upgrade_parts: upgrade_parts.clone(), repair_parts: repair_parts.clone() },
+    );
+}
+
+pub fn blueprint_set(env: &Env, ship_type: &Symbol, blueprint: &Blueprint) {
+    events::publish(
+        env,
+        BUILD,
+        SET,
+        BlueprintSet { ship_type: ship_type.clone(), blueprint: blueprint.clone() },
+    );
+}
+
+pub fn build_ordered(env: &Env, job: &BuildJob) {
+    events::publish(env, BUILD, CREATED, BuildChanged { job: job.clone() });
+}
+
+pub fn build_cancelled(env: &Env, job: &BuildJob) {
+    events::publish(env, BUILD, CANCELLED, BuildChanged { job: job.clone() });
+}
+
+pub fn build_claimed(env: &Env, job: &BuildJob) {
+    events::publish(env, BUILD, COMPLETED, BuildChanged { job: job.clone() });
+}
+
+pub fn module_set(env: &Env, module: &Symbol, spec: &Module) {
+    events::publish(env, MODULE, SET, ModuleSet { module: module.clone(), spec: spec.clone() });
+}
+
+pub fn module_installed(env: &Env, ship_id: u64, module: &Symbol) {
+    events::publish(env, MODULE, INSTALLED, ShipModuleChanged { ship_id, module: module.clone() });
+}
+
+pub fn module_removed(env: &Env, ship_id: u64, module: &Symbol) {
+    events::publish(env, MODULE, REMOVED, ShipModuleChanged { ship_id, module: module.clone() });
+}
+
+pub fn cargo_loaded(env: &Env, ship_id: u64, resource: &Symbol, amount: u64) {
+    events::publish(env, CARGO, LOADED, CargoMoved { ship_id, resource: resource.clone(), amount });
+}
+
+pub fn cargo_unloaded(env: &Env, ship_id: u64, resource: &Symbol, amount: u64) {
+    events::publish(env, CARGO, UNLOADED, CargoMoved { ship_id, resource: resource.clone(), amount });
+}
+
+pub fn cargo_transferred(env: &Env, from_ship: u64, to_ship: u64, resource: &Symbol, amount: u64) {
+    events::publish(
+        env,
+        CARGO,
+        TRANSFER,
+        CargoTransferred { from_ship, to_ship, resource: resource.clone(), amount },
+    );
+}
@@ -0,0 +1,1278 @@
+#![no_std]
+mod error;
+pub mod events;
+mod migration;
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+
+use game_common::interfaces::Scanner;
+use game_common::ledger::LedgerError;
+use game_common::{access, math, registry, resources, storage, upgrade};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, InvokeError,
+    Address, BytesN, Env, Map, Symbol, Vec,
+};
+
+// Layout version of the records this code writes
+pub const SCHEMA_VERSION: u32 = 6;
+
+// Ships are launched at the star system's first star
+pub const HOME_STAR: &str = "ALPHA_CENTAURI";
+
+// Mining rate of ships launched before blueprints, and what each upgrade
+// added to it
+pub const BASE_MINING_RATE: u64 = 100;
+pub const MINING_RATE_PER_LEVEL: u64 = 50;
+
+// Seconds a ship's drills need between mining runs
+pub const MINING_COOLDOWN: u64 = 3_600;
+
+// Upgrade and repair costs are paid in this resource
+pub const SERVICE_RESOURCE: Symbol = resources::IRON;
+
+// Health restored by one set of repair parts
+pub const REPAIR_UNIT: u64 = 10;
+
+// Share of a build's cost returned when it is cancelled
+pub const BUILD_REFUND_PERCENT: u64 = 50;
+
+// Ships gain a module slot every this many levels
+pub const LEVELS_PER_SLOT: u64 = 3;
+
+// Ships burn fuel in proportion to the distance they travel, and take
+// TRAVEL_TIME_PER_DISTANCE seconds per unit of distance divided by speed
+pub const FUEL_RESOURCE: Symbol = resources::ENERGY;
+pub const FUEL_PER_DISTANCE: u64 = 10;
+pub const TRAVEL_TIME_PER_DISTANCE: u64 = 36_000;
+
+// Scan range of a ship without scanner modules
+pub const BASE_SCAN_RANGE: u64 = 5;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Ship(u64),
+    PlayerShips(Address),
+    ShipCounter,
+    UpgradeCosts,
+    RepairCosts,
+    MiningReadyAt(u64),
+    Recipe(Symbol),
+    RecipeList,
+    CraftCounter,
+    Craft(u64),
+    PlayerCrafts(Address),
+    Blueprint(Symbol),
+    BuildCounter,
+    Build(u64),
+    PlayerBuilds(Address),
+    Module(Symbol),
+    ShipModules(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ship {
+    pub id: u64,
+    pub owner: Address,
+    pub name: Symbol,
+    pub type_: Symbol,
+    pub health: u64,
+    pub max_health: u64,
+    pub cargo_capacity: u64,
+    pub level: u64,
+    pub resources: Map<Symbol, u64>,
+    pub built_at: u64,
+    pub location: Symbol, // last star the ship docked at
+    pub mining_rate: u64,
+    pub speed: u64,
+    pub fuel_capacity: u64,
+    pub module_slots: u32,
+    pub fuel: u64,
+    // Set while under way from `location`; the ship docks there once
+    // `arrive` is called after `arrives_at`
+    pub destination: Option<Symbol>,
+    pub departed_at: u64,
+    pub arrives_at: u64,
+}
+
+// Turns `inputs` from a player's balance into `output_amount` of `output`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Recipe {
+    pub inputs: Map<Symbol, u64>,
+    pub output: Symbol,
+    pub output_amount: u64,
+    pub craft_time: u64,           // seconds; 0 crafts instantly
+    pub ship_type: Option<Symbol>, // hull the crafting must run on, if any
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CraftJob {
+    pub id: u64,
+    pub player: Address,
+    pub recipe: Symbol,
+    pub output: Symbol,
+    pub amount: u64,
+    pub ready_at: u64,
+    pub claimed: bool,
+}
+
+// What each upgrade adds to a ship, and the level upgrades stop at
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeCurve {
+    pub health: u64,
+    pub cargo_capacity: u64,
+    pub fuel_capacity: u64,
+    pub mining_rate: u64,
+    pub max_level: u64,
+}
+
+// Base stats, cost and build time of a ship type
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Blueprint {
+    pub health: u64,
+    pub cargo_capacity: u64,
+    pub speed: u64,
+    pub fuel_capacity: u64,
+    pub mining_rate: u64,
+    pub module_slots: u32,
+    pub build_cost: Map<Symbol, u64>,
+    pub build_time: u64, // seconds
+    pub upgrade: UpgradeCurve,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BuildStatus {
+    Building,
+    Ready,
+    Cancelled,
+    Claimed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildJob {
+    pub id: u64,
+    pub owner: Address,
+    pub name: Symbol,
+    pub type_: Symbol,
+    pub cost: Map<Symbol, u64>,
+    pub ready_at: u64,
+    pub status: BuildStatus,
+}
+
+// What a module is fitted in. A ship carries at most one module of each
+// slot type.
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SlotType {
+    Engine,
+    Cargo,
+    Mining,
+    Scanner,
+    Shield,
+    Weapon,
+}
+
+// Stats a ship has with its modules fitted, or a module adds to them
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ShipStats {
+    pub cargo_capacity: u64,
+    pub speed: u64,
+    pub fuel_capacity: u64,
+    pub mining_rate: u64,
+    pub scan_range: u64,
+    pub shield: u64,
+    pub attack: u64,
+}
+
+// An installable module. Modules are items in the registry, held in the
+// owner's Economy balance while not fitted to a ship.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Module {
+    pub slot: SlotType,
+    pub bonus: ShipStats,
+}
+
+#[contract]
+pub struct ShipyardContract;
+
+#[contractimpl]
+impl ShipyardContract {
+    // Admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        access::initialize(&env, &admin)?;
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::transfer_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Ok(access::read_admin(&env)?)
+    }
+
+    pub fn set_operator(env: Env, operator: Address, enabled: bool) -> Result<(), Error> {
+        access::set_operator(&env, &operator, enabled)?;
+        Ok(())
+    }
+
+    pub fn is_operator(env: Env, account: Address) -> bool {
+        access::is_operator(&env, &account)
+    }
+
+    // Upgrades
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, wasm_hash)?;
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    // Registry
+    pub fn set_registry(env: Env, registry: Address) -> Result<(), Error> {
+        registry::set_registry(&env, &registry)?;
+        Ok(())
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, Error> {
+        Ok(registry::read_registry(&env)?)
+    }
+
+    // Rewrite up to `limit` ship records, starting at `start_id`, into the
+    // current layout. Returns the number of ships rewritten.
+    pub fn migrate(env: Env, start_id: u64, limit: u32) -> Result<u32, Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        // Version 3 keeps the ship counter in instance storage
+        storage::move_to_instance::<_, u64>(&env, &DataKey::ShipCounter);
+
+        let last_id = Self::get_ship_counter(&env)
+            .min(start_id.saturating_add(limit as u64).saturating_sub(1));
+        let mut migrated = 0;
+        for id in start_id.max(1)..=last_id {
+            if let Some(ship) = migration::load_ship(&env, id) {
+                storage::write_persistent(&env, &DataKey::Ship(id), &ship);
+                migrated += 1;
+            }
+        }
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(migrated)
+    }
+
+    // Ship Management
+
+    // Add or replace the blueprint for `ship_type`. Admin only; types
+    // without a blueprint cannot be built or upgraded.
+    pub fn set_blueprint(env: Env, ship_type: Symbol, blueprint: Blueprint) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        if blueprint.health == 0
+            || blueprint.cargo_capacity == 0
+            || blueprint.speed == 0
+            || blueprint.upgrade.max_level == 0
+        {
+            return Err(Error::InvalidBlueprint);
+        }
+        for resource in blueprint.build_cost.keys() {
+            registry::resource(&env, &resource)?;
+        }
+        env.storage().instance().set(&DataKey::Blueprint(ship_type.clone()), &blueprint);
+        events::blueprint_set(&env, &ship_type, &blueprint);
+
+        Ok(())
+    }
+
+    pub fn get_blueprint(env: Env, ship_type: Symbol) -> Result<Blueprint, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Blueprint(ship_type))
+            .ok_or(Error::UnknownShipType)
+    }
+
+    // Pay for a ship of `type_` from the owner's balance and queue it.
+    // Returns the build id; the ship is claimed with `claim_ship` once the
+    // type's build time has passed.
+    pub fn order_ship(env: Env, owner: Address, name: Symbol, type_: Symbol) -> Result<u64, Error> {
+        owner.require_auth();
+        storage::extend_instance(&env);
+
+        let blueprint = Self::get_blueprint(env.clone(), type_.clone())?;
+        Self::consume_parts(&env, &owner, &blueprint.build_cost, 1)?;
+
+        let id = env
+            .storage()
+            .instance()
+            .get::<_, u64>(&DataKey::BuildCounter)
+            .unwrap_or(0)
+            + 1;
+        let job = BuildJob {
+            id,
+            owner: owner.clone(),
+            name,
+            type_,
+            cost: blueprint.build_cost,
+            ready_at: env.ledger().timestamp() + blueprint.build_time,
+            status: BuildStatus::Building,
+        };
+        storage::write_persistent(&env, &DataKey::Build(id), &job);
+        env.storage().instance().set(&DataKey::BuildCounter, &id);
+
+        let mut builds = Self::get_player_builds(env.clone(), owner.clone());
+        builds.push_back(id);
+        storage::write_persistent(&env, &DataKey::PlayerBuilds(owner), &builds);
+
+        events::build_ordered(&env, &job);
+
+        Ok(id)
+    }
+
+    // The build as it stands; a build whose time has passed reads as Ready
+    pub fn get_build(env: Env, build_id: u64) -> Result<BuildJob, Error> {
+        let mut job: BuildJob =
+            storage::read_persistent(&env, &DataKey::Build(build_id)).ok_or(Error::BuildNotFound)?;
+        if job.status == BuildStatus::Building && env.ledger().timestamp() >= job.ready_at {
+            job.status = BuildStatus::Ready;
+        }
+        Ok(job)
+    }
+
+    pub fn get_player_builds(env: Env, player: Address) -> Vec<u64> {
+        storage::read_persistent(&env, &DataKey::PlayerBuilds(player)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Abandon an unclaimed build. BUILD_REFUND_PERCENT of each cost, rounded
+    // down, goes back to the owner; returns what was refunded.
+    pub fn cancel_build(env: Env, owner: Address, build_id: u64) -> Result<Map<Symbol, u64>, Error> {
+        owner.require_auth();
+        storage::extend_instance(&env);
+
+        let mut job = Self::open_build(&env, &owner, build_id)?;
+
+        let mut refund = Map::new(&env);
+        for (resource, amount) in job.cost.iter() {
+            let amount = amount.checked_mul(BUILD_REFUND_PERCENT).ok_or(Error::Overflow)? / 100;
+            if amount > 0 {
+                refund.set(resource, amount);
+            }
+        }
+        if !refund.is_empty() {
+            for (resource, amount) in refund.iter() {
+                Self::credit(&env, &owner, &resource, amount)?;
+            }
+        }
+
+        job.status = BuildStatus::Cancelled;
+        storage::write_persistent(&env, &DataKey::Build(build_id), &job);
+        events::build_cancelled(&env, &job);
+
+        Ok(refund)
+    }
+
+    // Launch a finished build and return the new ship's id
+    pub fn claim_ship(env: Env, owner: Address, build_id: u64) -> Result<u64, Error> {
+        owner.require_auth();
+        storage::extend_instance(&env);
+
+        let mut job = Self::open_build(&env, &owner, build_id)?;
+        if env.ledger().timestamp() < job.ready_at {
+            return Err(Error::BuildNotReady);
+        }
+
+        let ship_id = Self::launch_ship(&env, &owner, job.name.clone(), job.type_.clone())?;
+        job.status = BuildStatus::Claimed;
+        storage::write_persistent(&env, &DataKey::Build(build_id), &job);
+        events::build_claimed(&env, &job);
+
+        Ok(ship_id)
+    }
+
+    pub fn get_ship(env: Env, id: u64) -> Result<Ship, Error> {
+        migration::load_ship(&env, id).ok_or(Error::ShipNotFound)
+    }
+
+    pub fn get_player_ships(env: Env, player: Address) -> Vec<u64> {
+        storage::read_persistent(&env, &DataKey::PlayerShips(player)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Ship Upgrades
+    pub fn upgrade_ship(env: Env, player: Address, ship_id: u64) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let mut ship = Self::get_ship(env.clone(), ship_id)?;
+        
+        if ship.owner != player {
+            return Err(Error::NotShipOwner);
+        }
+
+        let (curve, charge) = Self::next_upgrade(&env, &ship)?;
+        Self::consume_parts(&env, &player, &charge, 1)?;
+
+        ship.level += 1;
+        ship.max_health += curve.health;
+        ship.cargo_capacity += curve.cargo_capacity;
+        ship.fuel_capacity += curve.fuel_capacity;
+        ship.mining_rate += curve.mining_rate;
+        ship.health = ship.max_health;
+
+        storage::write_persistent(&env, &DataKey::Ship(ship_id), &ship);
+
+        events::ship_upgraded(&env, &ship, &charge);
+
+        Ok(())
+    }
+
+    // Ship Repairs
+    pub fn repair_ship(env: Env, player: Address, ship_id: u64) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let mut ship = Self::get_ship(env.clone(), ship_id)?;
+        
+        if ship.owner != player {
+            return Err(Error::NotShipOwner);
+        }
+
+        let charge = Self::repair_charge(&env, &ship)?;
+        Self::consume_parts(&env, &player, &charge, 1)?;
+
+        ship.health = ship.max_health;
+        storage::write_persistent(&env, &DataKey::Ship(ship_id), &ship);
+
+        events::ship_repaired(&env, &ship, &charge);
+
+        Ok(())
+    }
+
+    // What `upgrade_ship` would charge for the ship's next level
+    pub fn quote_upgrade(env: Env, ship_id: u64) -> Result<Map<Symbol, u64>, Error> {
+        let ship = Self::get_ship(env.clone(), ship_id)?;
+        Ok(Self::next_upgrade(&env, &ship)?.1)
+    }
+
+    // What `repair_ship` would charge to restore the ship to full health
+    pub fn quote_repair(env: Env, ship_id: u64) -> Result<Map<Symbol, u64>, Error> {
+        let ship = Self::get_ship(env.clone(), ship_id)?;
+        Self::repair_charge(&env, &ship)
+    }
+
+    // Modules
+
+    // Add or replace what `module` does when fitted. Admin only; the module
+    // must be a resource in the registry.
+    pub fn set_module(env: Env, module: Symbol, spec: Module) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        registry::resource(&env, &module)?;
+        env.storage().instance().set(&DataKey::Module(module.clone()), &spec);
+        events::module_set(&env, &module, &spec);
+
+        Ok(())
+    }
+
+    pub fn get_module(env: Env, module: Symbol) -> Result<Module, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Module(module))
+            .ok_or(Error::UnknownModule)
+    }
+
+    pub fn get_ship_modules(env: Env, ship_id: u64) -> Vec<Symbol> {
+        storage::read_persistent(&env, &DataKey::ShipModules(ship_id)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn get_module_slots(env: Env, ship_id: u64) -> Result<u32, Error> {
+        let ship = Self::get_ship(env, ship_id)?;
+        Ok(Self::module_slots(&ship))
+    }
+
+    // The ship's stats with its modules fitted
+    pub fn get_ship_stats(env: Env, ship_id: u64) -> Result<ShipStats, Error> {
+        let ship = Self::get_ship(env.clone(), ship_id)?;
+        Self::ship_stats(&env, &ship, &Self::get_ship_modules(env.clone(), ship_id))
+    }
+
+    // Where the ship's sensors reach, for the star system's discovery checks
+    pub fn get_scanner(env: Env, ship_id: u64) -> Result<Scanner, Error> {
+        let ship = Self::get_ship(env.clone(), ship_id)?;
+        let stats = Self::ship_stats(&env, &ship, &Self::get_ship_modules(env.clone(), ship_id))?;
+        Ok(Scanner {
+            owner: ship.owner,
+            star: match ship.destination {
+                Some(_) => None,
+                None => Some(ship.location),
+            },
+            scan_range: stats.scan_range,
+        })
+    }
+
+    // Move one `module` from the player's Economy balance onto their ship.
+    // The ship needs a free slot and no other module of the same slot type.
+    pub fn install_module(env: Env, player: Address, ship_id: u64, module: Symbol) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let ship = Self::get_ship(env.clone(), ship_id)?;
+        if ship.owner != player {
+            return Err(Error::NotShipOwner);
+        }
+
+        let spec = Self::get_module(env.clone(), module.clone())?;
+        let mut fitted = Self::get_ship_modules(env.clone(), ship_id);
+        if fitted.len() >= Self::module_slots(&ship) {
+            return Err(Error::SlotsFull);
+        }
+        for other in fitted.iter() {
+            if Self::get_module(env.clone(), other)?.slot == spec.slot {
+                return Err(Error::SlotTaken);
+            }
+        }
+
+        Self::debit(&env, &player, &module, 1)?;
+
+        fitted.push_back(module.clone());
+        storage::write_persistent(&env, &DataKey::ShipModules(ship_id), &fitted);
+        events::module_installed(&env, ship_id, &module);
+
+        Ok(())
+    }
+
+    // Move a fitted module back into the player's Economy balance. Fails if
+    // the hold would no longer fit its cargo.
+    pub fn uninstall_module(env: Env, player: Address, ship_id: u64, module: Symbol) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let ship = Self::get_ship(env.clone(), ship_id)?;
+        if ship.owner != player {
+            return Err(Error::NotShipOwner);
+        }
+
+        let mut fitted = Self::get_ship_modules(env.clone(), ship_id);
+        let index = fitted.first_index_of(&module).ok_or(Error::ModuleNotInstalled)?;
+        fitted.remove(index);
+        let stats = Self::ship_stats(&env, &ship, &fitted)?;
+        if Self::cargo_mass(&env, &ship)? > stats.cargo_capacity {
+            return Err(Error::CargoOverCapacity);
+        }
+        if ship.fuel > stats.fuel_capacity {
+            return Err(Error::FuelOverCapacity);
+        }
+
+        Self::credit(&env, &player, &module, 1)?;
+
+        storage::write_persistent(&env, &DataKey::ShipModules(ship_id), &fitted);
+        events::module_removed(&env, ship_id, &module);
+
+        Ok(())
+    }
+
+    // Cargo
+    // Holds are measured by mass: each unit weighs its resource's unit mass
+    // in the registry, and a hold carries up to the ship's cargo capacity.
+
+    // Move `amount` of `resource` from the player's Economy balance into the
+    // ship's hold
+    pub fn load_cargo(
+        env: Env,
+        player: Address,
+        ship_id: u64,
+        resource: Symbol,
+        amount: u64,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let mut ship = Self::owned_ship(&env, &player, ship_id)?;
+        Self::require_docked(&ship)?;
+        Self::stow(&env, &mut ship, &resource, amount)?;
+
+        Self::debit(&env, &player, &resource, amount)?;
+
+        storage::write_persistent(&env, &DataKey::Ship(ship_id), &ship);
+        events::cargo_loaded(&env, ship_id, &resource, amount);
+
+        Ok(())
+    }
+
+    // Move `amount` of `resource` from the ship's hold into the player's
+    // Economy balance
+    pub fn unload_cargo(
+        env: Env,
+        player: Address,
+        ship_id: u64,
+        resource: Symbol,
+        amount: u64,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let mut ship = Self::owned_ship(&env, &player, ship_id)?;
+        Self::require_docked(&ship)?;
+        Self::unstow(&mut ship, &resource, amount)?;
+
+        Self::credit(&env, &player, &resource, amount)?;
+
+        storage::write_persistent(&env, &DataKey::Ship(ship_id), &ship);
+        events::cargo_unloaded(&env, ship_id, &resource, amount);
+
+        Ok(())
+    }
+
+    // Move cargo between two of the player's ships at the same star
+    pub fn transfer_cargo(
+        env: Env,
+        player: Address,
+        from_ship: u64,
+        to_ship: u64,
+        resource: Symbol,
+        amount: u64,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        if from_ship == to_ship {
+            return Err(Error::SameShip);
+        }
+        let mut from = Self::owned_ship(&env, &player, from_ship)?;
+        let mut to = Self::owned_ship(&env, &player, to_ship)?;
+        Self::require_docked(&from)?;
+        Self::require_docked(&to)?;
+        if from.location != to.location {
+            return Err(Error::ShipsApart);
+        }
+
+        Self::unstow(&mut from, &resource, amount)?;
+        Self::stow(&env, &mut to, &resource, amount)?;
+
+        storage::write_persistent(&env, &DataKey::Ship(from_ship), &from);
+        storage::write_persistent(&env, &DataKey::Ship(to_ship), &to);
+        events::cargo_transferred(&env, from_ship, to_ship, &resource, amount);
+
+        Ok(())
+    }
+
+    // Mass of everything in the ship's hold
+    pub fn get_cargo_mass(env: Env, ship_id: u64) -> Result<u64, Error> {
+        let ship = Self::get_ship(env.clone(), ship_id)?;
+        Self::cargo_mass(&env, &ship)
+    }
+
+    // Navigation
+
+    // Fill the ship's tank with `amount` of FUEL_RESOURCE from the player's
+    // Economy balance. Returns the fuel now in the tank.
+    pub fn refuel(env: Env, player: Address, ship_id: u64, amount: u64) -> Result<u64, Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let mut ship = Self::owned_ship(&env, &player, ship_id)?;
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let capacity = Self::ship_stats(&env, &ship, &Self::get_ship_modules(env.clone(), ship_id))?.fuel_capacity;
+        let fuel = ship.fuel.checked_add(amount).ok_or(Error::Overflow)?;
+        if fuel > capacity {
+            return Err(Error::FuelTankFull);
+        }
+
+        Self::debit(&env, &player, &FUEL_RESOURCE, amount)?;
+
+        ship.fuel = fuel;
+        storage::write_persistent(&env, &DataKey::Ship(ship_id), &ship);
+        events::ship_refueled(&env, &ship);
+
+        Ok(fuel)
+    }
+
+    // Set off from the ship's star for `destination`, burning
+    // FUEL_PER_DISTANCE fuel per unit of distance. Returns the arrival time.
+    pub fn travel(env: Env, player: Address, ship_id: u64, destination: Symbol) -> Result<u64, Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let mut ship = Self::owned_ship(&env, &player, ship_id)?;
+        Self::require_docked(&ship)?;
+        if ship.location == destination {
+            return Err(Error::AlreadyAtStar);
+        }
+
+        let distance = match registry::star_system(&env)?.try_get_distance(&ship.location, &destination) {
+            Ok(Ok(distance)) => distance,
+            _ => return Err(Error::StarNotFound),
+        };
+        let burn = distance.checked_mul(FUEL_PER_DISTANCE).ok_or(Error::Overflow)?;
+        if burn > ship.fuel {
+            return Err(Error::NotEnoughFuel);
+        }
+        let speed = Self::ship_stats(&env, &ship, &Self::get_ship_modules(env.clone(), ship_id))?.speed;
+        let duration = distance
+            .checked_mul(TRAVEL_TIME_PER_DISTANCE)
+            .ok_or(Error::Overflow)?
+            .div_ceil(speed);
+
+        let now = env.ledger().timestamp();
+        ship.fuel -= burn;
+        ship.destination = Some(destination);
+        ship.departed_at = now;
+        ship.arrives_at = now + duration;
+        storage::write_persistent(&env, &DataKey::Ship(ship_id), &ship);
+        events::ship_departed(&env, &ship);
+
+        Ok(now + duration)
+    }
+
+    // Dock the ship at its destination once its arrival time has passed.
+    // Anyone may call this; for a ship already docked it does nothing.
+    // Returns the star the ship is at.
+    pub fn arrive(env: Env, ship_id: u64) -> Result<Symbol, Error> {
+        storage::extend_instance(&env);
+
+        let mut ship = Self::get_ship(env.clone(), ship_id)?;
+        let Some(destination) = ship.destination.clone() else {
+            return Ok(ship.location);
+        };
+        if env.ledger().timestamp() < ship.arrives_at {
+            return Err(Error::NotArrived);
+        }
+
+        ship.location = destination;
+        ship.destination = None;
+        storage::write_persistent(&env, &DataKey::Ship(ship_id), &ship);
+        events::ship_arrived(&env, &ship);
+
+        Ok(ship.location)
+    }
+
+    // How far through its voyage the ship is, in percent. Docked ships are
+    // at 100.
+    pub fn get_travel_progress(env: Env, ship_id: u64) -> Result<u32, Error> {
+        let ship = Self::get_ship(env.clone(), ship_id)?;
+        if ship.destination.is_none() {
+            return Ok(100);
+        }
+        let total = ship.arrives_at - ship.departed_at;
+        let elapsed = env.ledger().timestamp().saturating_sub(ship.departed_at);
+        if total == 0 || elapsed >= total {
+            return Ok(100);
+        }
+        Ok((elapsed as u128 * 100 / total as u128) as u32)
+    }
+
+    // Mining
+    // Mine `resource` at `star` into the ship's cargo and return the amount
+    // mined. The ship must be at the star; the yield is capped by its mining
+    // rate, the units that fit in its hold and what the deposit holds.
+    pub fn mine(
+        env: Env,
+        player: Address,
+        ship_id: u64,
+        star: Symbol,
+        resource: Symbol,
+    ) -> Result<u64, Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let mut ship = Self::get_ship(env.clone(), ship_id)?;
+
+        if ship.owner != player {
+            return Err(Error::NotShipOwner);
+        }
+        Self::require_docked(&ship)?;
+        if ship.location != star {
+            return Err(Error::ShipNotAtStar);
+        }
+
+        let now = env.ledger().timestamp();
+        let ready_at = env
+            .storage()
+            .temporary()
+            .get::<_, u64>(&DataKey::MiningReadyAt(ship_id))
+            .unwrap_or(0);
+        if now < ready_at {
+            return Err(Error::MiningCooldown);
+        }
+
+        let stats = Self::ship_stats(&env, &ship, &Self::get_ship_modules(env.clone(), ship_id))?;
+        let unit_mass = registry::resource(&env, &resource)?.unit_mass;
+        let free_units = stats.cargo_capacity.saturating_sub(Self::cargo_mass(&env, &ship)?) / unit_mass;
+        if free_units == 0 {
+            return Err(Error::CargoFull);
+        }
+
+        let star_system = registry::star_system(&env)?;
+        if star_system.get_resource_amount(&star, &resource) <= 0 {
+            return Err(Error::DepositEmpty);
+        }
+
+        let wanted = free_units.min(stats.mining_rate);
+        let mined = star_system.extract(
+            &env.current_contract_address(),
+            &star,
+            &resource,
+            &(wanted as i128),
+        ) as u64;
+
+        let held = ship.resources.get(resource.clone()).unwrap_or(0);
+        ship.resources.set(resource.clone(), held + mined);
+        storage::write_persistent(&env, &DataKey::Ship(ship_id), &ship);
+
+        let cooldown_ledgers = (MINING_COOLDOWN / storage::LEDGER_CLOSE_TIME) as u32;
+        storage::write_temporary(
+            &env,
+            &DataKey::MiningReadyAt(ship_id),
+            &(now + MINING_COOLDOWN),
+            cooldown_ledgers,
+        );
+
+        events::resource_mined(&env, ship_id, &star, &resource, mined);
+
+        Ok(mined)
+    }
+
+    // Crafting
+    pub fn set_recipe(env: Env, caller: Address, recipe_id: Symbol, recipe: Recipe) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        if recipe.inputs.is_empty()
+            || recipe.output_amount == 0
+            || recipe.inputs.values().contains(0)
+        {
+            return Err(Error::InvalidRecipe);
+        }
+        for resource in recipe.inputs.keys() {
+            registry::resource(&env, &resource)?;
+        }
+        registry::resource(&env, &recipe.output)?;
+
+        let key = DataKey::Recipe(recipe_id.clone());
+        if !env.storage().persistent().has(&key) {
+            let mut recipes = Self::get_recipes(env.clone());
+            recipes.push_back(recipe_id.clone());
+            env.storage().instance().set(&DataKey::RecipeList, &recipes);
+        }
+        storage::write_persistent(&env, &key, &recipe);
+        events::recipe_set(&env, &recipe_id, &recipe);
+
+        Ok(())
+    }
+
+    pub fn get_recipe(env: Env, recipe_id: Symbol) -> Result<Recipe, Error> {
+        storage::read_persistent(&env, &DataKey::Recipe(recipe_id)).ok_or(Error::RecipeNotFound)
+    }
+
+    pub fn get_recipes(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RecipeList)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Spend a recipe's inputs from the player's balance and start a craft.
+    // Recipes tied to a hull type run on `ship_id`, which must be one of the
+    // player's ships of that type. Returns the craft's id; crafts with no
+    // crafting time are paid out straight away.
+    pub fn craft(
+        env: Env,
+        player: Address,
+        recipe_id: Symbol,
+        ship_id: Option<u64>,
+    ) -> Result<u64, Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let recipe = Self::get_recipe(env.clone(), recipe_id.clone())?;
+        if let Some(ship_type) = &recipe.ship_type {
+            let ship = Self::get_ship(env.clone(), ship_id.ok_or(Error::WrongShipType)?)?;
+            if ship.owner != player {
+                return Err(Error::NotShipOwner);
+            }
+            if ship.type_ != *ship_type {
+                return Err(Error::WrongShipType);
+            }
+        }
+
+        Self::consume_parts(&env, &player, &recipe.inputs, 1)?;
+
+        let id = env
+            .storage()
+            .instance()
+            .get::<_, u64>(&DataKey::CraftCounter)
+            .unwrap_or(0)
+            + 1;
+        let mut job = CraftJob {
+            id,
+            player: player.clone(),
+            recipe: recipe_id,
+            output: recipe.output,
+            amount: recipe.output_amount,
+            ready_at: env.ledger().timestamp() + recipe.craft_time,
+            claimed: false,
+        };
+        env.storage().instance().set(&DataKey::CraftCounter, &id);
+
+        let mut crafts = Self::get_player_crafts(env.clone(), player.clone());
+        crafts.push_back(id);
+        storage::write_persistent(&env, &DataKey::PlayerCrafts(player), &crafts);
+
+        events::craft_started(&env, &job);
+        if recipe.craft_time == 0 {
+            Self::complete_craft(&env, &mut job)?;
+        }
+        storage::write_persistent(&env, &DataKey::Craft(id), &job);
+
+        Ok(id)
+    }
+
+    // Pay out a finished craft to the player who started it
+    pub fn claim_craft(env: Env, player: Address, craft_id: u64) -> Result<u64, Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        let mut job = Self::get_craft(env.clone(), craft_id)?;
+        if job.player != player {
+            return Err(Error::NotCraftOwner);
+        }
+        if job.claimed {
+            return Err(Error::CraftClaimed);
+        }
+        if env.ledger().timestamp() < job.ready_at {
+            return Err(Error::CraftNotReady);
+        }
+
+        Self::complete_craft(&env, &mut job)?;
+        storage::write_persistent(&env, &DataKey::Craft(craft_id), &job);
+
+        Ok(job.amount)
+    }
+
+    pub fn get_craft(env: Env, craft_id: u64) -> Result<CraftJob, Error> {
+        storage::read_persistent(&env, &DataKey::Craft(craft_id)).ok_or(Error::CraftNotFound)
+    }
+
+    pub fn get_player_crafts(env: Env, player: Address) -> Vec<u64> {
+        storage::read_persistent(&env, &DataKey::PlayerCrafts(player)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Crafted parts consumed by upgrades (per current level) and repairs
+    // (per REPAIR_UNIT of damage). Empty until set, so both are free of parts.
+    pub fn set_service_parts(
+        env: Env,
+        caller: Address,
+        upgrade_parts: Map<Symbol, u64>,
+        repair_parts: Map<Symbol, u64>,
+    ) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        for resource in upgrade_parts.keys().iter().chain(repair_parts.keys().iter()) {
+            registry::resource(&env, &resource)?;
+        }
+        env.storage().instance().set(&DataKey::UpgradeCosts, &upgrade_parts);
+        env.storage().instance().set(&DataKey::RepairCosts, &repair_parts);
+        events::service_parts_set(&env, &upgrade_parts, &repair_parts);
+
+        Ok(())
+    }
+
+    pub fn get_upgrade_parts(env: Env) -> Map<Symbol, u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::UpgradeCosts)
+            .unwrap_or_else(|| Map::new(&env))
+    }
+
+    pub fn get_repair_parts(env: Env) -> Map<Symbol, u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RepairCosts)
+            .unwrap_or_else(|| Map::new(&env))
+    }
+
+    // Cost Calculations
+    fn calculate_upgrade_cost(current_level: u64) -> Result<u64, Error> {
+        // Base cost * (level ^ 1.5)
+        let base_cost = math::from_int(1000)?;
+        let level_factor = math::pow(math::from_int(current_level as i128)?, 15 * math::SCALE / 10)?;
+        let cost = math::to_int(math::mul(base_cost, level_factor)?);
+        u64::try_from(cost).map_err(|_| Error::Overflow)
+    }
+
+    fn calculate_repair_cost(damage: u64) -> Result<u64, Error> {
+        // Cost per health point * damage
+        let cost_per_health = 10;
+        damage.checked_mul(cost_per_health).ok_or(Error::Overflow)
+    }
+
+    // The upgrade curve of the ship's type and the bill for its next level:
+    // the upgrade cost plus the upgrade parts once per current level
+    fn next_upgrade(env: &Env, ship: &Ship) -> Result<(UpgradeCurve, Map<Symbol, u64>), Error> {
+        let curve = Self::get_blueprint(env.clone(), ship.type_.clone())?.upgrade;
+        if ship.level >= curve.max_level {
+            return Err(Error::MaxLevel);
+        }
+        let cost = Self::calculate_upgrade_cost(ship.level)?;
+        let parts = Self::get_upgrade_parts(env.clone());
+        let charge = Self::service_charge(env, cost, &parts, ship.level)?;
+        Ok((curve, charge))
+    }
+
+    // The bill for repairing all of the ship's damage: the repair cost plus
+    // one set of repair parts per REPAIR_UNIT of damage, rounded up
+    fn repair_charge(env: &Env, ship: &Ship) -> Result<Map<Symbol, u64>, Error> {
+        if ship.health >= ship.max_health {
+            return Err(Error::ShipNotDamaged);
+        }
+        let damage = ship.max_health - ship.health;
+        let cost = Self::calculate_repair_cost(damage)?;
+        let parts = Self::get_repair_parts(env.clone());
+        Self::service_charge(env, cost, &parts, damage.div_ceil(REPAIR_UNIT))
+    }
+
+    // `cost` of SERVICE_RESOURCE and `parts`, `times` over, as one bill
+    fn service_charge(
+        env: &Env,
+        cost: u64,
+        parts: &Map<Symbol, u64>,
+        times: u64,
+    ) -> Result<Map<Symbol, u64>, Error> {
+        let mut charge = Map::new(env);
+        if cost > 0 {
+            charge.set(SERVICE_RESOURCE, cost);
+        }
+        for (resource, amount) in parts.iter() {
+            let amount = amount.checked_mul(times).ok_or(Error::Overflow)?;
+            if amount == 0 {
+                continue;
+            }
+            let total = charge
+                .get(resource.clone())
+                .unwrap_or(0)
+                .checked_add(amount)
+                .ok_or(Error::Overflow)?;
+            charge.set(resource, total);
+        }
+        Ok(charge)
+    }
+
+    // Helper functions
+    // Launch a new ship with its type's base stats
+    fn launch_ship(env: &Env, owner: &Address, name: Symbol, type_: Symbol) -> Result<u64, Error> {
+        let blueprint = Self::get_blueprint(env.clone(), type_.clone())?;
+        let counter = Self::get_ship_counter(env);
+        let new_counter = counter + 1;
+
+        let ship = Ship {
+            id: new_counter,
+            owner: owner.clone(),
+            name,
+            type_,
+            health: blueprint.health,
+            max_health: blueprint.health,
+            cargo_capacity: blueprint.cargo_capacity,
+            level: 1,
+            resources: Map::new(env),
+            built_at: env.ledger().timestamp(),
+            location: Symbol::new(env, HOME_STAR),
+            mining_rate: blueprint.mining_rate,
+            speed: blueprint.speed,
+            fuel_capacity: blueprint.fuel_capacity,
+            module_slots: blueprint.module_slots,
+            fuel: blueprint.fuel_capacity,
+            destination: None,
+            departed_at: 0,
+            arrives_at: 0,
+        };
+
+        storage::write_persistent(env, &DataKey::Ship(new_counter), &ship);
+        env.storage().instance().set(&DataKey::ShipCounter, &new_counter);
+
+        // Add ship to player's ships
+        let mut player_ships = Self::get_player_ships(env.clone(), owner.clone());
+        player_ships.push_back(new_counter);
+        storage::write_persistent(env, &DataKey::PlayerShips(owner.clone()), &player_ships);
+
+        events::ship_created(env, &ship);
+
+        Ok(new_counter)
+    }
+
+    // A build of `owner`'s that can still be claimed or cancelled
+    fn open_build(env: &Env, owner: &Address, build_id: u64) -> Result<BuildJob, Error> {
+        let job = Self::get_build(env.clone(), build_id)?;
+        if job.owner != *owner {
+            return Err(Error::NotBuildOwner);
+        }
+        if matches!(job.status, BuildStatus::Cancelled | BuildStatus::Claimed) {
+            return Err(Error::BuildClosed);
+        }
+        Ok(job)
+    }
+
+    // Debit `parts`, `times` over, from the player's balance in the Economy.
+    // A short balance fails the whole call.
+    fn consume_parts(env: &Env, player: &Address, parts: &Map<Symbol, u64>, times: u64) -> Result<(), Error> {
+        if parts.is_empty() || times == 0 {
+            return Ok(());
+        }
+        for (resource, amount) in parts.iter() {
+            let amount = amount.checked_mul(times).ok_or(Error::Overflow)?;
+            Self::debit(env, player, &resource, amount)?;
+        }
+        Ok(())
+    }
+
+    // Take `amount` of `resource` from the player's Economy balance
+    fn debit(env: &Env, player: &Address, resource: &Symbol, amount: u64) -> Result<(), Error> {
+        let economy = registry::economy(env)?;
+        Self::ledger_result(env, economy.try_debit(&env.current_contract_address(), player, resource, &amount))
+    }
+
+    // Give `amount` of `resource` to the player's Economy balance
+    fn credit(env: &Env, player: &Address, resource: &Symbol, amount: u64) -> Result<(), Error> {
+        let economy = registry::economy(env)?;
+        Self::ledger_result(env, economy.try_credit(&env.current_contract_address(), player, resource, &amount))
+    }
+
+    // A short balance in the Economy is this contract's InsufficientFunds and
+    // an overflowing one its Overflow. Anything else the Economy refuses
+    // traps as it did.
+    fn ledger_result<T>(env: &Env, result: Result<T, Result<soroban_sdk::Error, InvokeError>>) -> Result<(), Error> {
+        match result {
+            Ok(_) => Ok(()),
+            Err(Ok(error)) if error == soroban_sdk::Error::from_contract_error(LedgerError::InsufficientBalance.code()) => {
+                Err(Error::InsufficientFunds)
+            }
+            Err(Ok(error)) if error == soroban_sdk::Error::from_contract_error(LedgerError::Overflow.code()) => {
+                Err(Error::Overflow)
+            }
+            Err(Ok(error)) => panic_with_error!(env, error),
+            Err(Err(_)) => panic!("economy call aborted"),
+        }
+    }
+
+    fn complete_craft(env: &Env, job: &mut CraftJob) -> Result<(), Error> {
+        Self::credit(env, &job.player, &job.output, job.amount)?;
+        job.claimed = true;
+        events::craft_completed(env, job);
+        Ok(())
+    }
+
+    // The class's slots plus one for every LEVELS_PER_SLOT levels
+    fn module_slots(ship: &Ship) -> u32 {
+        ship.module_slots + (ship.level / LEVELS_PER_SLOT) as u32
+    }
+
+    // The ship's own stats plus the bonuses of `modules`
+    fn ship_stats(env: &Env, ship: &Ship, modules: &Vec<Symbol>) -> Result<ShipStats, Error> {
+        let mut stats = ShipStats {
+            cargo_capacity: ship.cargo_capacity,
+            speed: ship.speed,
+            fuel_capacity: ship.fuel_capacity,
+            mining_rate: ship.mining_rate,
+            scan_range: BASE_SCAN_RANGE,
+            ..Default::default()
+        };
+        for module in modules.iter() {
+            let bonus = Self::get_module(env.clone(), module)?.bonus;
+            stats.cargo_capacity = stats.cargo_capacity.saturating_add(bonus.cargo_capacity);
+            stats.speed = stats.speed.saturating_add(bonus.speed);
+            stats.fuel_capacity = stats.fuel_capacity.saturating_add(bonus.fuel_capacity);
+            stats.mining_rate = stats.mining_rate.saturating_add(bonus.mining_rate);
+            stats.scan_range = stats.scan_range.saturating_add(bonus.scan_range);
+            stats.shield = stats.shield.saturating_add(bonus.shield);
+            stats.attack = stats.attack.saturating_add(bonus.attack);
+        }
+        Ok(stats)
+    }
+
+    fn owned_ship(env: &Env, player: &Address, ship_id: u64) -> Result<Ship, Error> {
+        let ship = Self::get_ship(env.clone(), ship_id)?;
+        if ship.owner != *player {
+            return Err(Error::NotShipOwner);
+        }
+        Ok(ship)
+    }
+
+    // Ships under way cannot mine or move cargo until they arrive
+    fn require_docked(ship: &Ship) -> Result<(), Error> {
+        if ship.destination.is_some() {
+            return Err(Error::InTransit);
+        }
+        Ok(())
+    }
+
+    fn cargo_mass(env: &Env, ship: &Ship) -> Result<u64, Error> {
+        let mut mass: u64 = 0;
+        for (resource, amount) in ship.resources.iter() {
+            let unit_mass = registry::resource(env, &resource)?.unit_mass;
+            mass = amount
+                .checked_mul(unit_mass)
+                .and_then(|held| mass.checked_add(held))
+                .ok_or(Error::Overflow)?;
+        }
+        Ok(mass)
+    }
+
+    // Add cargo to the ship's hold, if its mass fits
+    fn stow(env: &Env, ship: &mut Ship, resource: &Symbol, amount: u64) -> Result<(), Error> {
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let unit_mass = registry::resource(env, resource)?.unit_mass;
+        let capacity = Self::ship_stats(env, ship, &Self::get_ship_modules(env.clone(), ship.id))?.cargo_capacity;
+        let held_mass = Self::cargo_mass(env, ship)?;
+        let mass = amount
+            .checked_mul(unit_mass)
+            .and_then(|added| added.checked_add(held_mass))
+            .ok_or(Error::Overflow)?;
+        if mass > capacity {
+            return Err(Error::Overloaded);
+        }
+        let held = ship.resources.get(resource.clone()).unwrap_or(0);
+        ship.resources.set(resource.clone(), held + amount);
+        Ok(())
+    }
+
+    // Take cargo out of the ship's hold
+    fn unstow(ship: &mut Ship, resource: &Symbol, amount: u64) -> Result<(), Error> {
+        if amount == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let held = ship.resources.get(resource.clone()).unwrap_or(0);
+        if held < amount {
+            return Err(Error::InsufficientCargo);
+        }
+        if held == amount {
+            ship.resources.remove(resource.clone());
+        } else {
+            ship.resources.set(resource.clone(), held - amount);
+        }
+        Ok(())
+    }
+
+    fn get_ship_counter(env: &Env) -> u64 {
+        storage::read_moved::<_, u64>(env, &DataKey::ShipCounter).unwrap_or(0)
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,186 @@
+use game_common::upgrade;
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, Symbol, TryFromVal, Val};
+
+use crate::{DataKey, Ship, BASE_MINING_RATE, HOME_STAR, MINING_RATE_PER_LEVEL};
+
+// Stats given to ships written before version 5, which had none of them
+pub const LEGACY_SPEED: u64 = 10;
+pub const LEGACY_FUEL_CAPACITY: u64 = 100;
+pub const LEGACY_MODULE_SLOTS: u32 = 2;
+
+// Ship layout written by schema version 1
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShipV1 {
+    pub id: u64,
+    pub owner: Address,
+    pub name: Symbol,
+    pub type_: Symbol,
+    pub health: u64,
+    pub max_health: u64,
+    pub cargo_capacity: u64,
+    pub level: u64,
+    pub resources: Map<Symbol, u64>,
+}
+
+// Ship layout written by schema versions 2 and 3
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShipV2 {
+    pub id: u64,
+    pub owner: Address,
+    pub name: Symbol,
+    pub type_: Symbol,
+    pub health: u64,
+    pub max_health: u64,
+    pub cargo_capacity: u64,
+    pub level: u64,
+    pub resources: Map<Symbol, u64>,
+    pub built_at: u64,
+}
+
+impl From<ShipV1> for ShipV2 {
+    fn from(ship: ShipV1) -> Self {
+        ShipV2 {
+            id: ship.id,
+            owner: ship.owner,
+            name: ship.name,
+            type_: ship.type_,
+            health: ship.health,
+            max_health: ship.max_health,
+            cargo_capacity: ship.cargo_capacity,
+            level: ship.level,
+            resources: ship.resources,
+            // Build time was not recorded before version 2
+            built_at: 0,
+        }
+    }
+}
+
+// Ship layout written by schema version 4
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShipV4 {
+    pub id: u64,
+    pub owner: Address,
+    pub name: Symbol,
+    pub type_: Symbol,
+    pub health: u64,
+    pub max_health: u64,
+    pub cargo_capacity: u64,
+    pub level: u64,
+    pub resources: Map<Symbol, u64>,
+    pub built_at: u64,
+    pub location: Symbol,
+    pub mining_rate: u64,
+}
+
+impl ShipV2 {
+    // Ships had no position before version 4; they are all at home
+    fn upgrade(self, env: &Env) -> ShipV4 {
+        ShipV4 {
+            id: self.id,
+            owner: self.owner,
+            name: self.name,
+            type_: self.type_,
+            health: self.health,
+            max_health: self.max_health,
+            cargo_capacity: self.cargo_capacity,
+            mining_rate: BASE_MINING_RATE
+                + MINING_RATE_PER_LEVEL * self.level.saturating_sub(1),
+            level: self.level,
+            resources: self.resources,
+            built_at: self.built_at,
+            location: Symbol::new(env, HOME_STAR),
+        }
+    }
+}
+
+// Ship layout written by schema version 5
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShipV5 {
+    pub id: u64,
+    pub owner: Address,
+    pub name: Symbol,
+    pub type_: Symbol,
+    pub health: u64,
+    pub max_health: u64,
+    pub cargo_capacity: u64,
+    pub level: u64,
+    pub resources: Map<Symbol, u64>,
+    pub built_at: u64,
+    pub location: Symbol,
+    pub mining_rate: u64,
+    pub speed: u64,
+    pub fuel_capacity: u64,
+    pub module_slots: u32,
+}
+
+impl From<ShipV4> for ShipV5 {
+    fn from(ship: ShipV4) -> Self {
+        ShipV5 {
+            id: ship.id,
+            owner: ship.owner,
+            name: ship.name,
+            type_: ship.type_,
+            health: ship.health,
+            max_health: ship.max_health,
+            cargo_capacity: ship.cargo_capacity,
+            level: ship.level,
+            resources: ship.resources,
+            built_at: ship.built_at,
+            location: ship.location,
+            mining_rate: ship.mining_rate,
+            // Ships launched before blueprints get the legacy hull's stats
+            speed: LEGACY_SPEED,
+            fuel_capacity: LEGACY_FUEL_CAPACITY,
+            module_slots: LEGACY_MODULE_SLOTS,
+        }
+    }
+}
+
+impl From<ShipV5> for Ship {
+    fn from(ship: ShipV5) -> Self {
+        Ship {
+            id: ship.id,
+            owner: ship.owner,
+            name: ship.name,
+            type_: ship.type_,
+            health: ship.health,
+            max_health: ship.max_health,
+            cargo_capacity: ship.cargo_capacity,
+            level: ship.level,
+            resources: ship.resources,
+            built_at: ship.built_at,
+            location: ship.location,
+            mining_rate: ship.mining_rate,
+            speed: ship.speed,
+            fuel_capacity: ship.fuel_capacity,
+            module_slots: ship.module_slots,
+            // Ships written before version 6 are docked with a full tank
+            fuel: ship.fuel_capacity,
+            destination: None,
+            departed_at: 0,
+            arrives_at: 0,
+        }
+    }
+}
+
+// Read a ship record in whichever layout it was written
+pub fn load_ship(env: &Env, id: u64) -> Option<Ship> {
+    let record: Val = env.storage().persistent().get(&DataKey::Ship(id))?;
+
+    if upgrade::has_field(env, &record, symbol_short!("fuel")) {
+        Some(Ship::try_from_val(env, &record).unwrap())
+    } else if upgrade::has_field(env, &record, symbol_short!("speed")) {
+        Some(ShipV5::try_from_val(env, &record).unwrap().into())
+    } else if upgrade::has_field(env, &record, symbol_short!("location")) {
+        Some(ShipV5::from(ShipV4::try_from_val(env, &record).unwrap()).into())
+    } else if upgrade::has_field(env, &record, symbol_short!("built_at")) {
+        Some(ShipV5::from(ShipV2::try_from_val(env, &record).unwrap().upgrade(env)).into())
+    } else {
+        let ship = ShipV2::from(ShipV1::try_from_val(env, &record).unwrap());
+        Some(ShipV5::from(ship.upgrade(env)).into())
+    }
+}
@@ -0,0 +1,458 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+fn setup(env: &Env) -> (Address, ShipyardContractClient<'_>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ShipyardContract);
+    let client = ShipyardContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (admin, client)
+}
+
+// A free hull with the stats ships had before blueprints
+fn blueprint(env: &Env, build_time: u64) -> Blueprint {
+    Blueprint {
+        health: 100,
+        cargo_capacity: 1000,
+        speed: 10,
+        fuel_capacity: 100,
+        mining_rate: BASE_MINING_RATE,
+        module_slots: 2,
+        build_cost: Map::new(env),
+        build_time,
+        upgrade: UpgradeCurve {
+            health: 20,
+            cargo_capacity: 200,
+            fuel_capacity: 0,
+            mining_rate: MINING_RATE_PER_LEVEL,
+            max_level: 10,
+        },
+    }
+}
+
+// Order and claim a ship of a type that is free and instant to build
+fn launch(client: &ShipyardContractClient, owner: &Address, type_: &Symbol) -> u64 {
+    client.set_blueprint(type_, &blueprint(&client.env, 0));
+    let build_id = client.order_ship(owner, &symbol_short!("NOVA"), type_);
+    client.claim_ship(owner, &build_id)
+}
+
+#[test]
+fn test_upgrade_ship() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let ship_id = launch(&client, &owner, &symbol_short!("SCOUT"));
+    assert_eq!(
+        client.try_upgrade_ship(&other, &ship_id),
+        Err(Ok(Error::NotShipOwner))
+    );
+
+    // Upgrades are charged through the Economy, found via the registry
+    let mut charge = Map::new(&env);
+    charge.set(SERVICE_RESOURCE, 1_000);
+    assert_eq!(client.quote_upgrade(&ship_id), charge);
+    assert_eq!(client.try_upgrade_ship(&owner, &ship_id), Err(Ok(Error::RegistryNotSet)));
+    assert_eq!(client.get_ship(&ship_id).level, 1);
+    assert_eq!(client.try_upgrade_ship(&owner, &99), Err(Ok(Error::ShipNotFound)));
+    assert_eq!(client.try_quote_upgrade(&99), Err(Ok(Error::ShipNotFound)));
+}
+
+#[test]
+fn test_repair_undamaged_ship() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+
+    let ship_id = launch(&client, &owner, &symbol_short!("SCOUT"));
+    assert_eq!(
+        client.try_repair_ship(&owner, &ship_id),
+        Err(Ok(Error::ShipNotDamaged))
+    );
+    assert_eq!(client.try_quote_repair(&ship_id), Err(Ok(Error::ShipNotDamaged)));
+    assert_eq!(client.try_get_ship(&99), Err(Ok(Error::ShipNotFound)));
+}
+
+#[test]
+fn test_ship_actions_require_auth() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+
+    let ship_id = launch(&client, &owner, &symbol_short!("SCOUT"));
+
+    env.set_auths(&[]);
+    assert!(client
+        .try_order_ship(&owner, &symbol_short!("NOVA"), &symbol_short!("SCOUT"))
+        .is_err());
+    assert!(client.try_upgrade_ship(&owner, &ship_id).is_err());
+    assert!(client.try_repair_ship(&owner, &ship_id).is_err());
+    assert_eq!(client.get_ship(&ship_id).level, 1);
+}
+
+#[test]
+fn test_migrate_ships_from_v1() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+
+    // Simulate three ships written before schema versioning
+    env.as_contract(&client.address, || {
+        game_common::upgrade::set_schema_version(&env, 1);
+        for id in 1..=3u64 {
+            let legacy = migration::ShipV1 {
+                id,
+                owner: owner.clone(),
+                name: symbol_short!("NOVA"),
+                type_: symbol_short!("SCOUT"),
+                health: 60,
+                max_health: 100,
+                cargo_capacity: 1000,
+                level: id,
+                resources: Map::new(&env),
+            };
+            env.storage().persistent().set(&DataKey::Ship(id), &legacy);
+        }
+        env.storage().persistent().set(&DataKey::ShipCounter, &3u64);
+    });
+
+    // Old records stay readable and usable before the migration runs, and
+    // new ships continue the id sequence
+    assert_eq!(client.get_ship(&2).level, 2);
+    let mut charge = Map::new(&env);
+    charge.set(SERVICE_RESOURCE, 400);
+    assert_eq!(client.quote_repair(&3), charge);
+    assert_eq!(launch(&client, &owner, &symbol_short!("MINER")), 4);
+
+    assert_eq!(client.migrate(&1, &2), 2);
+    assert_eq!(client.migrate(&3, &10), 2);
+    assert_eq!(client.version(), SCHEMA_VERSION);
+
+    env.as_contract(&client.address, || {
+        let stored: Ship = env.storage().persistent().get(&DataKey::Ship(1)).unwrap();
+        assert_eq!(stored.level, 1);
+        assert_eq!(stored.health, 60);
+        assert_eq!(stored.built_at, 0);
+        assert_eq!(stored.location, Symbol::new(&env, HOME_STAR));
+        assert_eq!(stored.speed, migration::LEGACY_SPEED);
+        assert_eq!(stored.module_slots, migration::LEGACY_MODULE_SLOTS);
+        assert_eq!(stored.fuel, migration::LEGACY_FUEL_CAPACITY);
+        assert_eq!(stored.destination, None);
+        let stored: Ship = env.storage().persistent().get(&DataKey::Ship(3)).unwrap();
+        assert_eq!(stored.mining_rate, BASE_MINING_RATE + 2 * MINING_RATE_PER_LEVEL);
+    });
+
+    // The counter moved without losing ships launched before the migration
+    let ship_id = launch(&client, &owner, &symbol_short!("MINER"));
+    assert_eq!(ship_id, 5);
+    assert_eq!(client.get_ship(&4).type_, symbol_short!("MINER"));
+}
+
+#[test]
+fn test_cost_curves() {
+    // 1000 * level^1.5, pinned so every host agrees on the exact cost
+    let costs: [u64; 6] = [1, 2, 3, 4, 9, 10].map(|level| {
+        ShipyardContract::calculate_upgrade_cost(level).unwrap()
+    });
+    assert_eq!(costs, [1_000, 2_828, 5_196, 8_000, 27_000, 31_622]);
+
+    assert_eq!(ShipyardContract::calculate_repair_cost(40), Ok(400));
+    assert_eq!(
+        ShipyardContract::calculate_repair_cost(u64::MAX),
+        Err(Error::Overflow)
+    );
+}
+
+#[test]
+fn test_set_registry() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let registry = Address::generate(&env);
+
+    assert_eq!(client.try_get_registry(), Err(Ok(Error::RegistryNotSet)));
+
+    env.set_auths(&[]);
+    assert!(client.try_set_registry(&registry).is_err());
+    env.mock_all_auths();
+
+    client.set_registry(&registry);
+    assert_eq!(client.get_registry(), registry);
+}
+
+#[test]
+fn test_mining_needs_the_ship_on_site() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let home = Symbol::new(&env, HOME_STAR);
+    let iron = symbol_short!("IRON");
+
+    let ship_id = launch(&client, &owner, &symbol_short!("MINER"));
+    assert_eq!(client.get_ship(&ship_id).location, home);
+
+    assert_eq!(
+        client.try_mine(&other, &ship_id, &home, &iron),
+        Err(Ok(Error::NotShipOwner))
+    );
+    assert_eq!(
+        client.try_mine(&owner, &ship_id, &Symbol::new(&env, "BARNARDS_STAR"), &iron),
+        Err(Ok(Error::ShipNotAtStar))
+    );
+    assert_eq!(client.try_mine(&owner, &99, &home, &iron), Err(Ok(Error::ShipNotFound)));
+
+    // Deposits are read from the star system through the registry
+    assert_eq!(
+        client.try_mine(&owner, &ship_id, &home, &iron),
+        Err(Ok(Error::RegistryNotSet))
+    );
+}
+
+#[test]
+fn test_recipes_are_validated() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let player = Address::generate(&env);
+    let plate = symbol_short!("PLATE");
+    let recipe = Recipe {
+        inputs: Map::new(&env),
+        output: symbol_short!("HULL"),
+        output_amount: 1,
+        craft_time: 0,
+        ship_type: None,
+    };
+
+    assert_eq!(client.try_get_recipe(&plate), Err(Ok(Error::RecipeNotFound)));
+    assert_eq!(client.try_craft(&player, &plate, &None), Err(Ok(Error::RecipeNotFound)));
+    assert_eq!(
+        client.try_set_recipe(&player, &plate, &recipe),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Recipes need inputs and a non-zero yield before resources are checked
+    assert_eq!(
+        client.try_set_recipe(&admin, &plate, &recipe),
+        Err(Ok(Error::InvalidRecipe))
+    );
+    let mut inputs = Map::new(&env);
+    inputs.set(symbol_short!("IRON"), 0);
+    assert_eq!(
+        client.try_set_recipe(&admin, &plate, &Recipe { inputs: inputs.clone(), ..recipe.clone() }),
+        Err(Ok(Error::InvalidRecipe))
+    );
+    inputs.set(symbol_short!("IRON"), 10);
+    assert_eq!(
+        client.try_set_recipe(&admin, &plate, &Recipe { inputs, ..recipe }),
+        Err(Ok(Error::RegistryNotSet))
+    );
+    assert_eq!(client.get_recipes().len(), 0);
+    assert_eq!(client.try_get_craft(&1), Err(Ok(Error::CraftNotFound)));
+}
+
+#[test]
+fn test_build_queue() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let scout = symbol_short!("SCOUT");
+    let order = || client.order_ship(&owner, &symbol_short!("NOVA"), &scout);
+
+    assert_eq!(
+        client.try_order_ship(&owner, &symbol_short!("NOVA"), &scout),
+        Err(Ok(Error::UnknownShipType))
+    );
+    client.set_blueprint(&scout, &blueprint(&env, 600));
+
+    // Builds are claimable once their time has passed, and only by the owner
+    let build_id = order();
+    assert_eq!(client.get_build(&build_id).status, BuildStatus::Building);
+    assert_eq!(client.try_claim_ship(&owner, &build_id), Err(Ok(Error::BuildNotReady)));
+    env.ledger().with_mut(|ledger| ledger.timestamp += 600);
+    assert_eq!(client.get_build(&build_id).status, BuildStatus::Ready);
+    assert_eq!(client.try_claim_ship(&other, &build_id), Err(Ok(Error::NotBuildOwner)));
+
+    let ship_id = client.claim_ship(&owner, &build_id);
+    assert_eq!(client.get_ship(&ship_id).type_, scout);
+    assert_eq!(client.get_build(&build_id).status, BuildStatus::Claimed);
+    assert_eq!(client.try_claim_ship(&owner, &build_id), Err(Ok(Error::BuildClosed)));
+    assert_eq!(client.try_cancel_build(&owner, &build_id), Err(Ok(Error::BuildClosed)));
+
+    // Cancelled builds can no longer be claimed
+    let build_id = order();
+    assert_eq!(client.cancel_build(&owner, &build_id), Map::new(&env));
+    assert_eq!(client.get_build(&build_id).status, BuildStatus::Cancelled);
+    env.ledger().with_mut(|ledger| ledger.timestamp += 600);
+    assert_eq!(client.try_claim_ship(&owner, &build_id), Err(Ok(Error::BuildClosed)));
+
+    assert_eq!(client.get_player_builds(&owner).len(), 2);
+    assert_eq!(client.get_player_ships(&owner).len(), 1);
+    assert_eq!(client.try_get_build(&99), Err(Ok(Error::BuildNotFound)));
+}
+
+#[test]
+fn test_blueprints_set_ship_stats() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let hauler = symbol_short!("HAULER");
+    let mut plan = blueprint(&env, 0);
+    plan.cargo_capacity = 5_000;
+    plan.speed = 4;
+    plan.module_slots = 6;
+    plan.upgrade.max_level = 1;
+
+    assert_eq!(client.try_get_blueprint(&hauler), Err(Ok(Error::UnknownShipType)));
+    assert_eq!(
+        client.try_set_blueprint(&hauler, &Blueprint { speed: 0, ..plan.clone() }),
+        Err(Ok(Error::InvalidBlueprint))
+    );
+    assert_eq!(
+        client.try_set_blueprint(&hauler, &Blueprint { health: 0, ..plan.clone() }),
+        Err(Ok(Error::InvalidBlueprint))
+    );
+
+    env.set_auths(&[]);
+    assert!(client.try_set_blueprint(&hauler, &plan).is_err());
+    env.mock_all_auths();
+
+    client.set_blueprint(&hauler, &plan);
+    assert_eq!(client.get_blueprint(&hauler), plan);
+
+    let ship_id = client.claim_ship(&owner, &client.order_ship(&owner, &symbol_short!("NOVA"), &hauler));
+    let ship = client.get_ship(&ship_id);
+    assert_eq!((ship.health, ship.max_health), (100, 100));
+    assert_eq!(ship.cargo_capacity, 5_000);
+    assert_eq!(ship.speed, 4);
+    assert_eq!(ship.fuel_capacity, 100);
+    assert_eq!(ship.module_slots, 6);
+
+    // Upgrades stop at the type's max level
+    assert_eq!(client.try_quote_upgrade(&ship_id), Err(Ok(Error::MaxLevel)));
+    assert_eq!(client.try_upgrade_ship(&owner, &ship_id), Err(Ok(Error::MaxLevel)));
+}
+
+#[test]
+fn test_module_slots_and_lookups() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let drive = symbol_short!("ION_DRIVE");
+    let spec = Module {
+        slot: SlotType::Engine,
+        bonus: ShipStats { speed: 5, ..Default::default() },
+    };
+
+    let ship_id = launch(&client, &owner, &symbol_short!("SCOUT"));
+    assert_eq!(client.get_module_slots(&ship_id), 2);
+    assert_eq!(client.get_ship_modules(&ship_id).len(), 0);
+    let stats = client.get_ship_stats(&ship_id);
+    assert_eq!((stats.cargo_capacity, stats.speed, stats.scan_range), (1_000, 10, BASE_SCAN_RANGE));
+    assert_eq!(
+        client.get_scanner(&ship_id),
+        Scanner {
+            owner: owner.clone(),
+            star: Some(Symbol::new(&env, HOME_STAR)),
+            scan_range: BASE_SCAN_RANGE,
+        }
+    );
+
+    assert_eq!(client.try_get_module(&drive), Err(Ok(Error::UnknownModule)));
+    assert_eq!(
+        client.try_install_module(&owner, &ship_id, &drive),
+        Err(Ok(Error::UnknownModule))
+    );
+    assert_eq!(
+        client.try_install_module(&other, &ship_id, &drive),
+        Err(Ok(Error::NotShipOwner))
+    );
+    assert_eq!(
+        client.try_uninstall_module(&owner, &ship_id, &drive),
+        Err(Ok(Error::ModuleNotInstalled))
+    );
+
+    // Modules are registry items, so defining one needs the registry
+    env.set_auths(&[]);
+    assert!(client.try_set_module(&drive, &spec).is_err());
+    env.mock_all_auths();
+    assert_eq!(client.try_set_module(&drive, &spec), Err(Ok(Error::RegistryNotSet)));
+}
+
+#[test]
+fn test_cargo_moves_are_checked() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let iron = symbol_short!("IRON");
+
+    let ship_id = launch(&client, &owner, &symbol_short!("SCOUT"));
+    assert_eq!(client.get_cargo_mass(&ship_id), 0);
+    assert_eq!(
+        client.try_load_cargo(&other, &ship_id, &iron, &10),
+        Err(Ok(Error::NotShipOwner))
+    );
+    assert_eq!(
+        client.try_load_cargo(&owner, &ship_id, &iron, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_unload_cargo(&owner, &ship_id, &iron, &10),
+        Err(Ok(Error::InsufficientCargo))
+    );
+    assert_eq!(
+        client.try_transfer_cargo(&owner, &ship_id, &ship_id, &iron, &10),
+        Err(Ok(Error::SameShip))
+    );
+
+    // Unit masses come from the registry
+    assert_eq!(
+        client.try_load_cargo(&owner, &ship_id, &iron, &10),
+        Err(Ok(Error::RegistryNotSet))
+    );
+}
+
+#[test]
+fn test_docked_ships_before_travel() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let owner = Address::generate(&env);
+    let home = Symbol::new(&env, HOME_STAR);
+
+    // New ships are docked at home with a full tank
+    let ship_id = launch(&client, &owner, &symbol_short!("SCOUT"));
+    let ship = client.get_ship(&ship_id);
+    assert_eq!((ship.fuel, ship.destination), (100, None));
+
+    // Arriving is a no-op for a docked ship
+    assert_eq!(client.arrive(&ship_id), home);
+    assert_eq!(client.arrive(&ship_id), home);
+    assert_eq!(client.get_travel_progress(&ship_id), 100);
+
+    assert_eq!(
+        client.try_travel(&owner, &ship_id, &home),
+        Err(Ok(Error::AlreadyAtStar))
+    );
+    assert_eq!(
+        client.try_refuel(&owner, &ship_id, &1),
+        Err(Ok(Error::FuelTankFull))
+    );
+    assert_eq!(client.try_refuel(&owner, &ship_id, &0), Err(Ok(Error::InvalidAmount)));
+
+    // Distances come from the star system
+    assert_eq!(
+        client.try_travel(&owner, &ship_id, &Symbol::new(&env, "BARNARDS_STAR")),
+        Err(Ok(Error::RegistryNotSet))
+    );
+    assert_eq!(client.try_arrive(&99), Err(Ok(Error::ShipNotFound)));
+}
@@ -0,0 +1,42 @@
+use game_common::access::AccessError;
+use game_common::registry::RegistryError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    PlayerExists = 1,
+    PlayerNotFound = 2,
+    InvalidAmount = 3,
+
+    NotInitialized = 100,
+    AlreadyInitialized = 101,
+    Unauthorized = 102,
+
+    RegistryNotSet = 120,
+    NotRegistered = 121,
+    UnknownResource = 122,
+    NotTradable = 123,
+}
+
+impl From<AccessError> for Error {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => Error::NotInitialized,
+            AccessError::AlreadyInitialized => Error::AlreadyInitialized,
+            AccessError::Unauthorized => Error::Unauthorized,
+        }
+    }
+}
+
+impl From<RegistryError> for Error {
+    fn from(error: RegistryError) -> Self {
+        match error {
+            RegistryError::RegistryNotSet => Error::RegistryNotSet,
+            RegistryError::NotRegistered => Error::NotRegistered,
+            RegistryError::UnknownResource => Error::UnknownResource,
+            RegistryError::NotTradable => Error::NotTradable,
+        }
+    }
+}
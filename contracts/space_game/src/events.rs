@@ -0,0 +1,33 @@
+use game_common::events::{self, JOINED, PLAYER, RESOURCE, SKIPPED};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+// (PLAYER, JOINED)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerJoined {
+    pub player: Address,
+    pub joined_at: u64,
+}
+
+// (RESOURCE, SKIPPED): `amount` of `resource` on an older player record
+// could not be credited to the ledger and was dropped when migrating it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceSkipped {
+    pub player: Address,
+    pub resource: Symbol,
+    pub amount: i128,
+}
+
+pub fn player_joined(env: &Env, player: &Address, joined_at: u64) {
+    events::publish(env, PLAYER, JOINED, PlayerJoined { player: player.clone(), joined_at });
+}
+
+pub fn resource_skipped(env: &Env, player: &Address, resource: &Symbol, amount: i128) {
+    events::publish(
+        env,
+        RESOURCE,
+        SKIPPED,
+        ResourceSkipped { player: player.clone(), resource: resource.clone(), amount },
+    );
+}
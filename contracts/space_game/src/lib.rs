@@ -0,0 +1,203 @@
+#![no_std]
+mod error;
+pub mod events;
+mod migration;
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+
+use game_common::registry::RegistryError;
+use game_common::{access, registry, storage, upgrade};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    Address, BytesN, Env, Map, Symbol, Vec,
+};
+
+// Layout version of the records this code writes
+pub const SCHEMA_VERSION: u32 = 3;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Player(Address),
+    Ship(Address, u32),
+    Resource(Symbol),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Player {
+    pub ships: Vec<Ship>,
+    pub joined_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ship {
+    pub name: Symbol,
+    pub ship_type: Symbol,
+    pub resources: Vec<Resource>,
+    pub active: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Resource {
+    pub name: Symbol,
+    pub amount: i128,
+    pub issuer: Address,
+}
+
+#[contract]
+pub struct SpaceGame;
+
+#[contractimpl]
+impl SpaceGame {
+    // Admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        access::initialize(&env, &admin)?;
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::transfer_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Ok(access::read_admin(&env)?)
+    }
+
+    pub fn set_operator(env: Env, operator: Address, enabled: bool) -> Result<(), Error> {
+        access::set_operator(&env, &operator, enabled)?;
+        Ok(())
+    }
+
+    pub fn is_operator(env: Env, account: Address) -> bool {
+        access::is_operator(&env, &account)
+    }
+
+    // Upgrades
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, wasm_hash)?;
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    // Registry
+    pub fn set_registry(env: Env, registry: Address) -> Result<(), Error> {
+        registry::set_registry(&env, &registry)?;
+        Ok(())
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, Error> {
+        Ok(registry::read_registry(&env)?)
+    }
+
+    // Rewrite a batch of player records into the current layout. Resources
+    // still held in version 1 and 2 records are credited to the economy
+    // ledger, so link the registry first. Entries the ledger cannot take are
+    // skipped with a RESOURCE SKIPPED event.
+    pub fn migrate(env: Env, players: Vec<Address>) -> Result<u32, Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        let mut migrated = 0;
+        for player in players.iter() {
+            if let Some((player_data, resources)) = migration::load_player_with_resources(&env, &player) {
+                for (resource, amount) in migration::total_resources(&env, &resources).iter() {
+                    Self::credit_legacy(&env, &player, &resource, amount)?;
+                }
+                storage::write_persistent(&env, &DataKey::Player(player), &player_data);
+                migrated += 1;
+            }
+        }
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(migrated)
+    }
+
+    // Initialize a new player
+    pub fn initialize_player(env: Env, player: Address) -> Result<(), Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        // Check if player already exists
+        if env.storage().persistent().has(&DataKey::Player(player.clone())) {
+            return Err(Error::PlayerExists);
+        }
+
+        // Create new player
+        let player_data = Player {
+            ships: Vec::new(&env),
+            joined_at: env.ledger().timestamp(),
+        };
+
+        // Store player data
+        storage::write_persistent(&env, &DataKey::Player(player.clone()), &player_data);
+        events::player_joined(&env, &player, player_data.joined_at);
+
+        Ok(())
+    }
+
+    // Create a new ship
+    // Order a ship for the player at the shipyard, which charges for it and
+    // queues the build. Returns the shipyard's build id.
+    pub fn create_ship(
+        env: Env,
+        player: Address,
+        name: Symbol,
+        ship_type: Symbol
+    ) -> Result<u64, Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        if migration::load_player(&env, &player).is_none() {
+            return Err(Error::PlayerNotFound);
+        }
+        Ok(registry::shipyard(&env)?.order_ship(&player, &name, &ship_type))
+    }
+
+    // Get the ships in the player's fleet. Ships are now built at the
+    // shipyard; this lists those created before the build queue.
+    pub fn get_player_ships(env: Env, player: Address) -> Vec<Ship> {
+        migration::load_player(&env, &player)
+            .map(|player_data| player_data.ships)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Get player's resources, as held in the economy ledger
+    pub fn get_player_resources(env: Env, player: Address) -> Result<Map<Symbol, u64>, Error> {
+        Ok(registry::economy(&env)?.get_player_resources(&player))
+    }
+
+    // Helper functions
+    // Older records were written without checking symbols or amounts, so
+    // unknown resources and amounts the ledger cannot hold are dropped
+    fn credit_legacy(env: &Env, player: &Address, resource: &Symbol, amount: i128) -> Result<(), Error> {
+        let known = match registry::resource(env, resource) {
+            Ok(_) => true,
+            Err(RegistryError::UnknownResource) => false,
+            Err(error) => return Err(error.into()),
+        };
+        // A balance the Economy refuses, e.g. one that would overflow, is
+        // skipped like any other rather than failing the whole batch
+        let credited = match u64::try_from(amount) {
+            Ok(units) if known && units > 0 => matches!(
+                registry::economy(env)?.try_credit(&env.current_contract_address(), player, resource, &units),
+                Ok(Ok(()))
+            ),
+            _ => false,
+        };
+        if !credited {
+            events::resource_skipped(env, player, resource, amount);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use game_common::upgrade;
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, Symbol, TryFromVal, Val, Vec};
+
+use crate::{DataKey, Player, Resource, Ship};
+
+// Player layout written by schema version 1
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerV1 {
+    pub ships: Vec<Ship>,
+    pub resources: Vec<Resource>,
+    pub initialized: bool,
+}
+
+// Player layout written by schema version 2
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerV2 {
+    pub ships: Vec<Ship>,
+    pub resources: Vec<Resource>,
+    pub joined_at: u64,
+}
+
+// Read a player record in whichever layout it was written
+pub fn load_player(env: &Env, player: &Address) -> Option<Player> {
+    load_player_with_resources(env, player).map(|(player_data, _)| player_data)
+}
+
+// As load_player, along with the resources older layouts kept on the
+// record before balances moved to the economy ledger
+pub fn load_player_with_resources(env: &Env, player: &Address) -> Option<(Player, Vec<Resource>)> {
+    let record: Val = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Player(player.clone()))?;
+
+    if !upgrade::has_field(env, &record, symbol_short!("resources")) {
+        return Some((Player::try_from_val(env, &record).unwrap(), Vec::new(env)));
+    }
+
+    if upgrade::has_field(env, &record, symbol_short!("joined_at")) {
+        let legacy = PlayerV2::try_from_val(env, &record).unwrap();
+        let player_data = Player { ships: legacy.ships, joined_at: legacy.joined_at };
+        Some((player_data, legacy.resources))
+    } else {
+        let legacy = PlayerV1::try_from_val(env, &record).unwrap();
+        // Join time was not recorded before version 2
+        let player_data = Player { ships: legacy.ships, joined_at: 0 };
+        Some((player_data, legacy.resources))
+    }
+}
+
+// Older records could hold several entries for one resource; add them up
+// so each resource is credited once
+pub fn total_resources(env: &Env, resources: &Vec<Resource>) -> Map<Symbol, i128> {
+    let mut totals = Map::new(env);
+    for resource in resources.iter() {
+        let total: i128 = totals.get(resource.name.clone()).unwrap_or(0);
+        totals.set(resource.name, total.saturating_add(resource.amount));
+    }
+    totals
+}
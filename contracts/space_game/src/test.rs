@@ -0,0 +1,311 @@
+#![cfg(test)]
+
+extern crate std;
+
+use super::*;
+use game_common::events::{EVENT_VERSION, RESOURCE, SKIPPED};
+use game_common::registry::Role;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{
+        storage::Persistent as _, Address as _, AuthorizedFunction, AuthorizedInvocation, Events,
+        Ledger,
+    },
+    Address, Env, IntoVal,
+};
+
+// Stands in for the Economy, keeping a plain balance per player and
+// resource
+#[contract]
+struct MockEconomy;
+
+#[contractimpl]
+impl MockEconomy {
+    pub fn get_balance(env: Env, player: Address, resource: Symbol) -> u64 {
+        env.storage().instance().get(&(player, resource)).unwrap_or(0)
+    }
+
+    // Traps on overflow, as the ledger refuses such a credit
+    pub fn credit(env: Env, _caller: Address, player: Address, resource: Symbol, amount: u64) {
+        let balance = Self::get_balance(env.clone(), player.clone(), resource.clone());
+        env.storage().instance().set(&(player, resource), &balance.checked_add(amount).unwrap());
+    }
+}
+
+fn setup(env: &Env) -> SpaceGameClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, SpaceGame);
+    SpaceGameClient::new(env, &contract_id)
+}
+
+#[test]
+fn test_initialize_player_twice() {
+    let env = Env::default();
+    let client = setup(&env);
+    let player = Address::generate(&env);
+
+    client.initialize_player(&player);
+    assert_eq!(
+        client.try_initialize_player(&player),
+        Err(Ok(Error::PlayerExists))
+    );
+}
+
+#[test]
+fn test_unknown_player() {
+    let env = Env::default();
+    let client = setup(&env);
+    let player = Address::generate(&env);
+
+    assert_eq!(
+        client.try_create_ship(&player, &symbol_short!("NOVA"), &symbol_short!("SCOUT")),
+        Err(Ok(Error::PlayerNotFound))
+    );
+    assert_eq!(client.get_player_ships(&player).len(), 0);
+}
+
+#[test]
+fn test_resources_live_in_economy() {
+    let env = Env::default();
+    let client = setup(&env);
+    let player = Address::generate(&env);
+
+    client.initialize_player(&player);
+    assert_eq!(client.try_get_player_resources(&player), Err(Ok(Error::RegistryNotSet)));
+}
+
+#[test]
+fn test_player_actions_require_auth() {
+    let env = Env::default();
+    let client = setup(&env);
+    let player = Address::generate(&env);
+
+    client.initialize_player(&player);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            player.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "initialize_player"),
+                    (player.clone(),).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+
+    env.set_auths(&[]);
+    let other = Address::generate(&env);
+    assert!(client.try_initialize_player(&other).is_err());
+    assert!(client
+        .try_create_ship(&player, &symbol_short!("NOVA"), &symbol_short!("SCOUT"))
+        .is_err());
+}
+
+#[test]
+fn test_migrate_players_from_v1() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.initialize(&admin);
+    assert_eq!(client.version(), SCHEMA_VERSION);
+
+    // Simulate a deployment that predates schema versioning
+    let ship = Ship {
+        name: symbol_short!("NOVA"),
+        ship_type: symbol_short!("SCOUT"),
+        resources: Vec::new(&env),
+        active: true,
+    };
+    env.as_contract(&client.address, || {
+        game_common::upgrade::set_schema_version(&env, 1);
+        let legacy = migration::PlayerV1 {
+            ships: Vec::from_array(&env, [ship.clone()]),
+            resources: Vec::new(&env),
+            initialized: true,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Player(player.clone()), &legacy);
+    });
+    assert_eq!(client.version(), 1);
+
+    // Old records stay readable before the migration runs
+    assert_eq!(client.get_player_ships(&player), Vec::from_array(&env, [ship.clone()]));
+
+    let migrated = client.migrate(&Vec::from_array(&env, [player.clone(), stranger.clone()]));
+    assert_eq!(migrated, 1);
+    assert_eq!(client.version(), SCHEMA_VERSION);
+
+    env.as_contract(&client.address, || {
+        let stored: Player = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Player(player.clone()))
+            .unwrap();
+        assert_eq!(stored.ships, Vec::from_array(&env, [ship.clone()]));
+        assert_eq!(stored.joined_at, 0);
+    });
+
+    // Migrated players keep playing normally; new ships are ordered from the
+    // shipyard, found through the registry
+    assert_eq!(
+        client.try_create_ship(&player, &symbol_short!("ORION"), &symbol_short!("MINER")),
+        Err(Ok(Error::RegistryNotSet))
+    );
+    assert_eq!(client.get_player_ships(&player).len(), 1);
+}
+
+#[test]
+fn test_migrate_players_from_v2() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+    client.initialize(&admin);
+
+    env.as_contract(&client.address, || {
+        game_common::upgrade::set_schema_version(&env, 2);
+        let legacy = migration::PlayerV2 {
+            ships: Vec::new(&env),
+            resources: Vec::new(&env),
+            joined_at: 42,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Player(player.clone()), &legacy);
+    });
+
+    assert_eq!(client.migrate(&Vec::from_array(&env, [player.clone()])), 1);
+    env.as_contract(&client.address, || {
+        let stored: Player = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Player(player.clone()))
+            .unwrap();
+        assert_eq!(stored, Player { ships: Vec::new(&env), joined_at: 42 });
+    });
+}
+
+#[test]
+fn test_migrate_skips_resources_the_ledger_rejects() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.initialize(&admin);
+
+    let registry = ::registry::RegistryClient::new(&env, &env.register_contract(None, ::registry::Registry));
+    registry.initialize(&admin);
+    let economy = MockEconomyClient::new(&env, &env.register_contract(None, MockEconomy));
+    registry.set_contract(&Role::Economy, &economy.address);
+    client.set_registry(&registry.address);
+    economy.credit(&admin, &player, &symbol_short!("ENERGY"), &u64::MAX);
+
+    // Older records took any symbol and any amount
+    let resource = |name: &str, amount: i128| Resource {
+        name: Symbol::new(&env, name),
+        amount,
+        issuer: admin.clone(),
+    };
+    env.as_contract(&client.address, || {
+        let legacy = migration::PlayerV2 {
+            ships: Vec::new(&env),
+            resources: Vec::from_array(
+                &env,
+                [
+                    resource("IRON", 30),
+                    resource("IRON", 20),
+                    resource("WATER", -5),
+                    resource("GOLD", 0),
+                    resource("UNOBTAIN", 10),
+                    resource("ENERGY", 10),
+                ],
+            ),
+            joined_at: 7,
+        };
+        env.storage().persistent().set(&DataKey::Player(player.clone()), &legacy);
+        let legacy = migration::PlayerV2 {
+            ships: Vec::new(&env),
+            resources: Vec::from_array(&env, [resource("ENERGY", 15)]),
+            joined_at: 8,
+        };
+        env.storage().persistent().set(&DataKey::Player(other.clone()), &legacy);
+    });
+
+    // The bad entries do not hold back the rest of the batch
+    assert_eq!(client.migrate(&Vec::from_array(&env, [player.clone(), other.clone()])), 2);
+    assert_eq!(economy.get_balance(&player, &symbol_short!("IRON")), 50);
+    assert_eq!(economy.get_balance(&player, &symbol_short!("ENERGY")), u64::MAX);
+    assert_eq!(economy.get_balance(&other, &symbol_short!("ENERGY")), 15);
+    assert_eq!(client.get_player_ships(&player), Vec::new(&env));
+
+    let skipped: Vec<(Symbol, i128)> = Vec::from_array(
+        &env,
+        [
+            (symbol_short!("WATER"), -5),
+            (symbol_short!("GOLD"), 0),
+            (Symbol::new(&env, "UNOBTAIN"), 10),
+            (symbol_short!("ENERGY"), 10),
+        ],
+    );
+    let mut published = Vec::new(&env);
+    for (source, topics, data) in env.events().all().iter() {
+        if source == client.address
+            && topics == (RESOURCE, SKIPPED, EVENT_VERSION).into_val(&env)
+        {
+            let event: crate::events::ResourceSkipped = data.into_val(&env);
+            assert_eq!(event.player, player);
+            published.push_back((event.resource, event.amount));
+        }
+    }
+    assert_eq!(published.len(), skipped.len());
+    for entry in skipped.iter() {
+        assert!(published.contains(&entry));
+    }
+}
+
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+
+    assert_eq!(
+        client.try_migrate(&Vec::new(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    client.initialize(&admin);
+    env.set_auths(&[]);
+    assert!(client.try_migrate(&Vec::new(&env)).is_err());
+    assert!(client
+        .try_upgrade(&soroban_sdk::BytesN::from_array(&env, &[0; 32]))
+        .is_err());
+}
+
+#[test]
+fn test_player_writes_extend_ttl() {
+    let env = Env::default();
+    let client = setup(&env);
+    let admin = Address::generate(&env);
+    let player = Address::generate(&env);
+    let key = DataKey::Player(player.clone());
+    let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+
+    client.initialize_player(&player);
+    assert_eq!(ttl(), storage::PERSISTENT_BUMP_AMOUNT);
+
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 2 * storage::DAY_IN_LEDGERS);
+    assert_eq!(ttl(), storage::PERSISTENT_BUMP_AMOUNT - 2 * storage::DAY_IN_LEDGERS);
+
+    client.initialize(&admin);
+    client.migrate(&Vec::from_array(&env, [player.clone()]));
+    assert_eq!(ttl(), storage::PERSISTENT_BUMP_AMOUNT);
+}
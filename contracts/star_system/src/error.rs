@@ -0,0 +1,54 @@
+use game_common::access::AccessError;
+use game_common::registry::RegistryError;
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    StarExists = 1,
+    StarNotFound = 2,
+    AlreadyDiscovered = 3,
+    InvalidAmount = 4,
+    DepositEmpty = 5,
+    DepositNotFound = 6,
+    GalaxyExists = 7,
+    GalaxyNotStarted = 8,
+    InvalidGalaxy = 9,
+    NotDiscovered = 10,
+    NotShipOwner = 11,
+    ShipInTransit = 12,
+    OutOfScanRange = 13,
+    SearchTooWide = 14,
+    ShipNotFound = 15,
+
+    NotInitialized = 100,
+    AlreadyInitialized = 101,
+    Unauthorized = 102,
+
+    RegistryNotSet = 120,
+    NotRegistered = 121,
+    UnknownResource = 122,
+    NotTradable = 123,
+}
+
+impl From<AccessError> for Error {
+    fn from(error: AccessError) -> Self {
+        match error {
+            AccessError::NotInitialized => Error::NotInitialized,
+            AccessError::AlreadyInitialized => Error::AlreadyInitialized,
+            AccessError::Unauthorized => Error::Unauthorized,
+        }
+    }
+}
+
+impl From<RegistryError> for Error {
+    fn from(error: RegistryError) -> Self {
+        match error {
+            RegistryError::RegistryNotSet => Error::RegistryNotSet,
+            RegistryError::NotRegistered => Error::NotRegistered,
+            RegistryError::UnknownResource => Error::UnknownResource,
+            RegistryError::NotTradable => Error::NotTradable,
+        }
+    }
+}
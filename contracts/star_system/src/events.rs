@@ -0,0 +1,136 @@
+use game_common::events::{
+    self, CREATED, DEPLETED, DEPOSIT, DISCOVERY, EXTRACTED, GALAXY, REPLENISH, SET, STAR,
+};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
+
+use crate::{Deposit, GalaxyConfig, Star};
+
+// (STAR, CREATED)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StarCreated {
+    pub star: Star,
+}
+
+// (GALAXY, CREATED): a galaxy was started from `config`; its sectors are
+// generated later, each star announcing itself with (STAR, CREATED)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GalaxyCreated {
+    pub config: GalaxyConfig,
+}
+
+// (STAR, DISCOVERY): `player` discovered `star`, ahead of everyone else
+// if `first`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StarDiscovered {
+    pub player: Address,
+    pub star: Symbol,
+    pub first: bool,
+}
+
+// (DEPOSIT, SET): the deposit of `resource` at `star` was reconfigured
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositConfigured {
+    pub star: Symbol,
+    pub resource: Symbol,
+    pub deposit: Deposit,
+}
+
+// (DEPOSIT, REPLENISH): the deposit of `resource` at `star` now holds
+// `amount`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceReplenished {
+    pub star: Symbol,
+    pub resource: Symbol,
+    pub amount: i128,
+}
+
+// (DEPOSIT, EXTRACTED): the deposit of `resource` at `star` was drawn
+// down and now holds `amount`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceExtracted {
+    pub star: Symbol,
+    pub resource: Symbol,
+    pub amount: i128,
+}
+
+// (DEPOSIT, DEPLETED): the deposit of `resource` at `star` is empty
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceDepleted {
+    pub star: Symbol,
+    pub resource: Symbol,
+}
+
+pub fn star_created(env: &Env, star: &Star) {
+    events::publish(env, STAR, CREATED, StarCreated { star: star.clone() });
+}
+
+pub fn galaxy_created(env: &Env, config: &GalaxyConfig) {
+    events::publish(env, GALAXY, CREATED, GalaxyCreated { config: config.clone() });
+}
+
+pub fn star_discovered(env: &Env, player: &Address, star: &Symbol, first: bool) {
+    events::publish(
+        env,
+        STAR,
+        DISCOVERY,
+        StarDiscovered { player: player.clone(), star: star.clone(), first },
+    );
+}
+
+pub fn deposit_configured(env: &Env, star: &Symbol, resource: &Symbol, deposit: &Deposit) {
+    events::publish(
+        env,
+        DEPOSIT,
+        SET,
+        DepositConfigured {
+            star: star.clone(),
+            resource: resource.clone(),
+            deposit: deposit.clone(),
+        },
+    );
+}
+
+// Announce the new size of a deposit
+pub fn deposit_changed(env: &Env, star: &Symbol, resource: &Symbol, amount: i128) {
+    if amount == 0 {
+        events::publish(
+            env,
+            DEPOSIT,
+            DEPLETED,
+            ResourceDepleted { star: star.clone(), resource: resource.clone() },
+        );
+    } else {
+        events::publish(
+            env,
+            DEPOSIT,
+            REPLENISH,
+            ResourceReplenished { star: star.clone(), resource: resource.clone(), amount },
+        );
+    }
+}
+
+// Announce what is left of a deposit after extraction
+pub fn deposit_drawn(env: &Env, star: &Symbol, resource: &Symbol, amount: i128) {
+    if amount == 0 {
+        events::publish(
+            env,
+            DEPOSIT,
+            DEPLETED,
+            ResourceDepleted { star: star.clone(), resource: resource.clone() },
+        );
+    } else {
+        events::publish(
+            env,
+            DEPOSIT,
+            EXTRACTED,
+            ResourceExtracted { star: star.clone(), resource: resource.clone(), amount },
+        );
+    }
+}
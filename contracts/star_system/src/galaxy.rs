@@ -0,0 +1,135 @@
+use game_common::resources::{self, Rarity};
+use soroban_sdk::{Bytes, Env, Symbol, Vec};
+
+use crate::{GalaxyConfig, SpectralClass, Star};
+
+// Edge length of the cube of space each sector covers
+pub const SECTOR_SIZE: u64 = 20;
+
+// Reseed the PRNG so a sector comes out the same for the same galaxy
+// seed, whichever batch generates it
+pub fn seed_sector(env: &Env, seed: u64, sector: u32) {
+    let mut input = Bytes::from_array(env, &seed.to_be_bytes());
+    input.extend_from_array(&sector.to_be_bytes());
+    env.prng().seed(env.crypto().sha256(&input).into());
+}
+
+// Sectors fill the smallest cube of sectors that holds them all
+fn grid_width(sectors: u32) -> u32 {
+    let mut width: u32 = 1;
+    while width.checked_pow(3).is_some_and(|cube| cube < sectors) {
+        width += 1;
+    }
+    width
+}
+
+pub fn sector_origin(config: &GalaxyConfig, sector: u32) -> (i32, i32, i32) {
+    let width = grid_width(config.sectors);
+    let corner = |cell: u32| (cell as u64 * SECTOR_SIZE) as i32;
+    (
+        corner(sector % width),
+        corner(sector / width % width),
+        corner(sector / (width * width)),
+    )
+}
+
+// Sectors of the galaxy overlapping the box that reaches `radius` either
+// side of `center`
+pub fn sectors_near(env: &Env, config: &GalaxyConfig, center: (i32, i32, i32), radius: u64) -> Vec<u32> {
+    let width = grid_width(config.sectors);
+    let span = |coordinate: i32| {
+        let low = (coordinate as i64 - radius as i64).max(0) / SECTOR_SIZE as i64;
+        let high = (coordinate as i64 + radius as i64)
+            .div_euclid(SECTOR_SIZE as i64)
+            .min(width as i64 - 1);
+        low as u32..(high + 1).max(low) as u32
+    };
+
+    let mut sectors = Vec::new(env);
+    for z in span(center.2) {
+        for y in span(center.1) {
+            for x in span(center.0) {
+                let sector = x + y * width + z * width * width;
+                if sector < config.sectors {
+                    sectors.push_back(sector);
+                }
+            }
+        }
+    }
+    sectors
+}
+
+pub fn star_count(env: &Env, config: &GalaxyConfig) -> u32 {
+    env.prng().gen_range::<u64>(1..=config.star_density as u64) as u32
+}
+
+// Generated stars are named after their sector and position in it,
+// e.g. S12_3
+pub fn star_name(env: &Env, sector: u32, index: u32) -> Symbol {
+    let mut name = [0u8; 22];
+    name[0] = b'S';
+    let mut len = push_digits(&mut name, 1, sector);
+    name[len] = b'_';
+    len = push_digits(&mut name, len + 1, index);
+    Symbol::new(env, core::str::from_utf8(&name[..len]).unwrap())
+}
+
+fn push_digits(buf: &mut [u8], start: usize, mut value: u32) -> usize {
+    let mut end = start;
+    loop {
+        buf[end] = b'0' + (value % 10) as u8;
+        end += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    buf[start..end].reverse();
+    end
+}
+
+// A star somewhere in the sector, without deposits
+pub fn roll_star(env: &Env, name: Symbol, origin: (i32, i32, i32)) -> Star {
+    let offset = || env.prng().gen_range::<u64>(0..SECTOR_SIZE) as i32;
+    Star {
+        name,
+        resources: Vec::new(env),
+        x: origin.0 + offset(),
+        y: origin.1 + offset(),
+        z: origin.2 + offset(),
+        class: roll_class(env),
+        discovered: false,
+    }
+}
+
+// Roughly as common as in the real sky: mostly red dwarfs, few giants
+fn roll_class(env: &Env) -> SpectralClass {
+    match env.prng().gen_range::<u64>(0..100) {
+        0 => SpectralClass::O,
+        1..=2 => SpectralClass::B,
+        3..=6 => SpectralClass::A,
+        7..=14 => SpectralClass::F,
+        15..=29 => SpectralClass::G,
+        30..=54 => SpectralClass::K,
+        _ => SpectralClass::M,
+    }
+}
+
+// The base resources found at a new star, with each deposit's capacity.
+// Rarer resources turn up less often.
+pub fn roll_deposits(env: &Env, richness: i128) -> Vec<(Symbol, i128)> {
+    let mut deposits = Vec::new(env);
+    for (resource, info) in resources::base_resources(env).iter() {
+        let chance = match info.rarity {
+            Rarity::Common => 75,
+            Rarity::Uncommon => 50,
+            Rarity::Rare => 25,
+            Rarity::Exotic => 10,
+        };
+        if env.prng().gen_range::<u64>(0..100) < chance {
+            let size = env.prng().gen_range::<u64>(1..=10) as i128;
+            deposits.push_back((resource, richness.saturating_mul(size)));
+        }
+    }
+    deposits
+}
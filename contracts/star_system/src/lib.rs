@@ -0,0 +1,659 @@
+#![no_std]
+mod error;
+pub mod events;
+mod galaxy;
+mod migration;
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+
+use game_common::{access, math, registry, storage, upgrade};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    Address, BytesN, Env, Symbol, Vec,
+};
+
+// Layout version of the records this code writes
+pub const SCHEMA_VERSION: u32 = 5;
+
+// Deposits regenerate at their rate per this many seconds
+pub const REGEN_PERIOD: u64 = 3_600;
+
+// Bounds on galaxy generation, so that a batch stays within a
+// transaction's budget
+pub const MAX_STARS_PER_SECTOR: u32 = 5;
+pub const MAX_SECTORS_PER_BATCH: u32 = 2;
+
+// Most sectors a galaxy can have, a cube 16 sectors across
+pub const MAX_SECTORS: u32 = 4_096;
+
+// Widest radius `get_stars_within` searches, so that it reads at most a
+// 3x3x3 block of sectors
+pub const MAX_SEARCH_RADIUS: u64 = galaxy::SECTOR_SIZE;
+
+// Stars per page of a player's discovery list
+pub const DISCOVERY_PAGE_SIZE: u32 = 50;
+
+// Generated deposits refill their capacity over this many REGEN_PERIODs
+pub const GENERATED_REFILL_PERIODS: i128 = 100;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Star(Symbol),
+    StarList,                         // stars placed by hand
+    SectorStars(u32),                 // sector -> where its generated stars are
+    ResourceAmount(Symbol, Symbol), // (star_name, resource_name)
+    Deposit(Symbol, Symbol),        // (star_name, resource_name)
+    Galaxy,
+    Discovered(Address, Symbol),      // (player, star_name) -> when they found it
+    DiscoveryCount(Address),
+    DiscoveryPage(Address, u32),      // (player, page) -> up to DISCOVERY_PAGE_SIZE stars
+    FirstDiscovery(Symbol),
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SpectralClass {
+    O,
+    B,
+    A,
+    F,
+    G,
+    K,
+    M,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Star {
+    pub name: Symbol,
+    pub resources: Vec<Symbol>,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub class: SpectralClass,
+    pub discovered: bool, // by anyone
+}
+
+// Who discovered a star first, and when
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Discovery {
+    pub player: Address,
+    pub discovered_at: u64,
+}
+
+// A generated star's place, kept in its sector's index so that searches
+// need not load every star
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StarPosition {
+    pub name: Symbol,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Star {
+    pub fn position(&self) -> (i32, i32, i32) {
+        (self.x, self.y, self.z)
+    }
+
+    // Straight-line distance to `other`, rounded down
+    pub fn distance_to(&self, other: &Star) -> u64 {
+        distance(self.position(), other.position())
+    }
+}
+
+fn distance(from: (i32, i32, i32), to: (i32, i32, i32)) -> u64 {
+    let dx = (from.0 as i128) - (to.0 as i128);
+    let dy = (from.1 as i128) - (to.1 as i128);
+    let dz = (from.2 as i128) - (to.2 as i128);
+    math::isqrt(dx * dx + dy * dy + dz * dz) as u64
+}
+
+// A resource deposit at a star. `amount` is the stock as of `updated_at`;
+// regeneration since then is applied whenever the deposit is read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deposit {
+    pub amount: i128,
+    pub capacity: i128,
+    pub regen_rate: i128, // units per REGEN_PERIOD
+    pub updated_at: u64,
+}
+
+impl Deposit {
+    // The deposit as it stands at `now`
+    pub fn regenerated(&self, now: u64) -> Deposit {
+        let mut deposit = self.clone();
+        if deposit.amount >= deposit.capacity || deposit.regen_rate <= 0 {
+            deposit.updated_at = now;
+            return deposit;
+        }
+
+        let elapsed = now.saturating_sub(deposit.updated_at) as i128;
+        let gained = deposit.regen_rate.saturating_mul(elapsed) / REGEN_PERIOD as i128;
+        if gained >= deposit.capacity - deposit.amount {
+            deposit.amount = deposit.capacity;
+            deposit.updated_at = now;
+        } else {
+            // Carry the time towards the next whole unit forward
+            deposit.amount += gained;
+            deposit.updated_at += (gained * REGEN_PERIOD as i128 / deposit.regen_rate) as u64;
+        }
+        deposit
+    }
+}
+
+// Parameters of a generated galaxy
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GalaxyConfig {
+    pub seed: u64,
+    pub sectors: u32,      // up to MAX_SECTORS
+    pub star_density: u32, // most stars in one sector, up to MAX_STARS_PER_SECTOR
+    pub richness: i128,    // deposit capacity per size step, 1 to 10 steps
+}
+
+// A galaxy being generated. Sectors below `next_sector` exist.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Galaxy {
+    pub config: GalaxyConfig,
+    pub next_sector: u32,
+}
+
+#[contract]
+pub struct StarSystem;
+
+#[contractimpl]
+impl StarSystem {
+    // Admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        access::initialize(&env, &admin)?;
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::transfer_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Ok(access::read_admin(&env)?)
+    }
+
+    pub fn set_operator(env: Env, operator: Address, enabled: bool) -> Result<(), Error> {
+        access::set_operator(&env, &operator, enabled)?;
+        Ok(())
+    }
+
+    pub fn is_operator(env: Env, account: Address) -> bool {
+        access::is_operator(&env, &account)
+    }
+
+    // Upgrades
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, wasm_hash)?;
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    // Registry
+    pub fn set_registry(env: Env, registry: Address) -> Result<(), Error> {
+        registry::set_registry(&env, &registry)?;
+        Ok(())
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, Error> {
+        Ok(registry::read_registry(&env)?)
+    }
+
+    // Bring storage written by older versions up to the current layout
+    pub fn migrate(env: Env) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        // Version 2 keeps the star list in instance storage
+        storage::move_to_instance::<_, Vec<Symbol>>(&env, &DataKey::StarList);
+
+        // Version 3 replaces bare resource amounts with deposits, and
+        // versions 4 and 5 place stars at coordinates and give them a class
+        for star_name in Self::get_all_stars(env.clone()).iter() {
+            if let Some(star) = migration::load_star(&env, &star_name) {
+                storage::write_persistent(&env, &DataKey::Star(star_name.clone()), &star);
+                for resource_name in star.resources.iter() {
+                    if let Some(deposit) = migration::load_deposit(&env, &star_name, &resource_name) {
+                        Self::write_deposit(&env, &star_name, &resource_name, &deposit);
+                    }
+                }
+            }
+        }
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(())
+    }
+
+    // Initialize star system
+    pub fn initialize_system(env: Env, caller: Address) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        // Create initial stars
+        let star = |name, x, y, z, class| Star {
+            name: Symbol::new(&env, name),
+            resources: Vec::new(&env),
+            x,
+            y,
+            z,
+            class,
+            discovered: false,
+        };
+        Self::create_star(&env, star("ALPHA_CENTAURI", 0, 0, 0, SpectralClass::G))?;
+        Self::create_star(&env, star("PROXIMA_CENTAURI", 2, 2, 3, SpectralClass::M))?;
+        Self::create_star(&env, star("BARNARDS_STAR", -4, 4, 2, SpectralClass::M))?;
+
+        Ok(())
+    }
+
+    // Set up a procedurally generated galaxy. Admin only, and only once;
+    // the sectors are then generated in batches with `generate_sectors`.
+    pub fn start_galaxy(env: Env, config: GalaxyConfig) -> Result<(), Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        if env.storage().instance().has(&DataKey::Galaxy) {
+            return Err(Error::GalaxyExists);
+        }
+        if config.sectors == 0
+            || config.sectors > MAX_SECTORS
+            || config.star_density == 0
+            || config.star_density > MAX_STARS_PER_SECTOR
+            || config.richness <= 0
+        {
+            return Err(Error::InvalidGalaxy);
+        }
+
+        events::galaxy_created(&env, &config);
+        let galaxy = Galaxy { config, next_sector: 0 };
+        env.storage().instance().set(&DataKey::Galaxy, &galaxy);
+        Ok(())
+    }
+
+    // Generate up to `limit` more sectors of the galaxy, capped at
+    // MAX_SECTORS_PER_BATCH. Returns the number of sectors still to go.
+    // Generated stars are listed by sector rather than in the star list.
+    pub fn generate_sectors(env: Env, limit: u32) -> Result<u32, Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        let mut progress = Self::get_galaxy(env.clone())?;
+        let config = progress.config.clone();
+        let end = config
+            .sectors
+            .min(progress.next_sector.saturating_add(limit.min(MAX_SECTORS_PER_BATCH)));
+        let now = env.ledger().timestamp();
+
+        for sector in progress.next_sector..end {
+            galaxy::seed_sector(&env, config.seed, sector);
+            let origin = galaxy::sector_origin(&config, sector);
+            let mut positions = Vec::new(&env);
+            for index in 0..galaxy::star_count(&env, &config) {
+                let name = galaxy::star_name(&env, sector, index);
+                let mut star = galaxy::roll_star(&env, name.clone(), origin);
+                for (resource, capacity) in galaxy::roll_deposits(&env, config.richness).iter() {
+                    let deposit = Deposit {
+                        amount: capacity,
+                        capacity,
+                        regen_rate: (capacity / GENERATED_REFILL_PERIODS).max(1),
+                        updated_at: now,
+                    };
+                    Self::write_deposit(&env, &name, &resource, &deposit);
+                    events::deposit_configured(&env, &name, &resource, &deposit);
+                    star.resources.push_back(resource);
+                }
+                positions.push_back(StarPosition { name, x: star.x, y: star.y, z: star.z });
+                Self::store_new_star(&env, &star)?;
+            }
+            storage::write_persistent(&env, &DataKey::SectorStars(sector), &positions);
+        }
+
+        progress.next_sector = end;
+        env.storage().instance().set(&DataKey::Galaxy, &progress);
+        Ok(config.sectors - end)
+    }
+
+    pub fn get_galaxy(env: Env) -> Result<Galaxy, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Galaxy)
+            .ok_or(Error::GalaxyNotStarted)
+    }
+
+    // The stars generated in `sector`, in the order they were made
+    pub fn get_sector_stars(env: Env, sector: u32) -> Vec<Symbol> {
+        let mut names = Vec::new(&env);
+        for position in Self::sector_index(&env, sector).iter() {
+            names.push_back(position.name);
+        }
+        names
+    }
+
+    // Store a new star placed by hand and add it to the star list
+    fn create_star(env: &Env, star: Star) -> Result<(), Error> {
+        Self::store_new_star(env, &star)?;
+
+        // Add to star list
+        let mut star_list: Vec<Symbol> = storage::read_moved(env, &DataKey::StarList)
+            .unwrap_or_else(|| Vec::new(env));
+        star_list.push_back(star.name);
+        env.storage().instance().set(&DataKey::StarList, &star_list);
+
+        Ok(())
+    }
+
+    // Record that `player` has discovered a star, scanning it from their
+    // docked ship `ship_id`. Returns whether they are the first to find it.
+    pub fn discover_star(env: Env, player: Address, ship_id: u64, star_name: Symbol) -> Result<bool, Error> {
+        player.require_auth();
+        storage::extend_instance(&env);
+
+        // Get star data
+        let mut star = migration::load_star(&env, &star_name).ok_or(Error::StarNotFound)?;
+
+        // Each player discovers a star once
+        let discovered = DataKey::Discovered(player.clone(), star_name.clone());
+        if env.storage().persistent().has(&discovered) {
+            return Err(Error::AlreadyDiscovered);
+        }
+
+        // The star must be within range of the ship's sensors
+        let scanner = match registry::shipyard(&env)?.try_get_scanner(&ship_id) {
+            Ok(Ok(scanner)) => scanner,
+            _ => return Err(Error::ShipNotFound),
+        };
+        if scanner.owner != player {
+            return Err(Error::NotShipOwner);
+        }
+        let from = scanner.star.ok_or(Error::ShipInTransit)?;
+        if Self::get_distance(env.clone(), from, star_name.clone())? > scanner.scan_range {
+            return Err(Error::OutOfScanRange);
+        }
+
+        // Record the discovery, and list it on the player's last page
+        storage::write_persistent(&env, &discovered, &env.ledger().timestamp());
+        let count = Self::get_discovery_count(env.clone(), player.clone());
+        let page_key = DataKey::DiscoveryPage(player.clone(), count / DISCOVERY_PAGE_SIZE);
+        let mut page: Vec<Symbol> =
+            storage::read_persistent(&env, &page_key).unwrap_or_else(|| Vec::new(&env));
+        page.push_back(star_name.clone());
+        storage::write_persistent(&env, &page_key, &page);
+        storage::write_persistent(&env, &DataKey::DiscoveryCount(player.clone()), &(count + 1));
+
+        // The first discoverer is credited for good
+        let first = !star.discovered;
+        if first {
+            star.discovered = true;
+            storage::write_persistent(&env, &DataKey::Star(star_name.clone()), &star);
+            let discovery = Discovery {
+                player: player.clone(),
+                discovered_at: env.ledger().timestamp(),
+            };
+            storage::write_persistent(&env, &DataKey::FirstDiscovery(star_name.clone()), &discovery);
+        }
+        events::star_discovered(&env, &player, &star_name, first);
+
+        Ok(first)
+    }
+
+    // One page of the stars `player` has discovered, in the order they
+    // found them, DISCOVERY_PAGE_SIZE to a page
+    pub fn get_discovered_by(env: Env, player: Address, page: u32) -> Vec<Symbol> {
+        storage::read_persistent(&env, &DataKey::DiscoveryPage(player, page)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    pub fn get_discovery_count(env: Env, player: Address) -> u32 {
+        storage::read_persistent(&env, &DataKey::DiscoveryCount(player)).unwrap_or(0)
+    }
+
+    pub fn has_discovered(env: Env, player: Address, star_name: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Discovered(player, star_name))
+    }
+
+    pub fn get_first_discovery(env: Env, star_name: Symbol) -> Result<Discovery, Error> {
+        storage::read_persistent(&env, &DataKey::FirstDiscovery(star_name)).ok_or(Error::NotDiscovered)
+    }
+
+    // Set the stock of a resource at a star, raising its capacity to fit
+    pub fn add_resource(
+        env: Env,
+        caller: Address,
+        star_name: Symbol,
+        resource_name: Symbol,
+        amount: i128
+    ) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        // Validate amount
+        if amount < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        registry::resource(&env, &resource_name)?;
+
+        let mut deposit = Self::open_deposit(&env, &star_name, &resource_name)?;
+        deposit.amount = amount;
+        deposit.capacity = deposit.capacity.max(amount);
+        Self::write_deposit(&env, &star_name, &resource_name, &deposit);
+        events::deposit_changed(&env, &star_name, &resource_name, amount);
+
+        Ok(())
+    }
+
+    // Set how much a deposit can hold and how fast it refills
+    pub fn configure_deposit(
+        env: Env,
+        caller: Address,
+        star_name: Symbol,
+        resource_name: Symbol,
+        capacity: i128,
+        regen_rate: i128,
+    ) -> Result<(), Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        if capacity < 0 || regen_rate < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        registry::resource(&env, &resource_name)?;
+
+        let mut deposit = Self::open_deposit(&env, &star_name, &resource_name)?;
+        deposit.capacity = capacity;
+        deposit.regen_rate = regen_rate;
+        deposit.amount = deposit.amount.min(capacity);
+        Self::write_deposit(&env, &star_name, &resource_name, &deposit);
+        events::deposit_configured(&env, &star_name, &resource_name, &deposit);
+
+        Ok(())
+    }
+
+    // Store regeneration since the deposit was last touched. Anyone may
+    // call this to have the replenished stock announced.
+    pub fn refresh_deposit(env: Env, star_name: Symbol, resource_name: Symbol) -> Result<i128, Error> {
+        storage::extend_instance(&env);
+
+        let deposit = Self::settle_deposit(&env, &star_name, &resource_name)
+            .ok_or(Error::DepositNotFound)?;
+        Ok(deposit.amount)
+    }
+
+    // Take up to `amount` of `resource_name` from the star's deposit and
+    // return how much was taken. Game contracts that mine are operators.
+    pub fn extract(
+        env: Env,
+        caller: Address,
+        star_name: Symbol,
+        resource_name: Symbol,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        access::require_operator(&env, &caller)?;
+        storage::extend_instance(&env);
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if !env.storage().persistent().has(&DataKey::Star(star_name.clone())) {
+            return Err(Error::StarNotFound);
+        }
+
+        let mut deposit = match Self::settle_deposit(&env, &star_name, &resource_name) {
+            Some(deposit) if deposit.amount > 0 => deposit,
+            _ => return Err(Error::DepositEmpty),
+        };
+
+        let taken = amount.min(deposit.amount);
+        deposit.amount -= taken;
+        Self::write_deposit(&env, &star_name, &resource_name, &deposit);
+        events::deposit_drawn(&env, &star_name, &resource_name, deposit.amount);
+
+        Ok(taken)
+    }
+
+    // Get star information
+    pub fn get_star_info(env: Env, star_name: Symbol) -> Result<Star, Error> {
+        migration::load_star(&env, &star_name).ok_or(Error::StarNotFound)
+    }
+
+    // Distance between two stars, in coordinate units, rounded down
+    pub fn get_distance(env: Env, from: Symbol, to: Symbol) -> Result<u64, Error> {
+        let from = Self::get_star_info(env.clone(), from)?;
+        let to = Self::get_star_info(env, to)?;
+        Ok(from.distance_to(&to))
+    }
+
+    // Other stars no further than `radius` from `star_name`: stars placed
+    // by hand in list order, then generated stars by sector. The radius is
+    // capped at MAX_SEARCH_RADIUS.
+    pub fn get_stars_within(env: Env, star_name: Symbol, radius: u64) -> Result<Vec<Symbol>, Error> {
+        if radius > MAX_SEARCH_RADIUS {
+            return Err(Error::SearchTooWide);
+        }
+        let center = Self::get_star_info(env.clone(), star_name.clone())?;
+        let mut nearby = Vec::new(&env);
+        for name in Self::get_all_stars(env.clone()).iter() {
+            if name == star_name {
+                continue;
+            }
+            if let Some(star) = migration::load_star(&env, &name) {
+                if center.distance_to(&star) <= radius {
+                    nearby.push_back(name);
+                }
+            }
+        }
+
+        // Only the sectors within reach are read
+        if let Ok(progress) = Self::get_galaxy(env.clone()) {
+            for sector in galaxy::sectors_near(&env, &progress.config, center.position(), radius).iter() {
+                if sector >= progress.next_sector {
+                    continue;
+                }
+                for position in Self::sector_index(&env, sector).iter() {
+                    let place = (position.x, position.y, position.z);
+                    if position.name != star_name && distance(center.position(), place) <= radius {
+                        nearby.push_back(position.name);
+                    }
+                }
+            }
+        }
+        Ok(nearby)
+    }
+
+    // Get resource amount, including regeneration not yet stored
+    pub fn get_resource_amount(env: Env, star_name: Symbol, resource_name: Symbol) -> i128 {
+        Self::get_deposit(env, star_name, resource_name)
+            .map(|deposit| deposit.amount)
+            .unwrap_or(0)
+    }
+
+    pub fn get_deposit(env: Env, star_name: Symbol, resource_name: Symbol) -> Result<Deposit, Error> {
+        let deposit = migration::load_deposit(&env, &star_name, &resource_name)
+            .ok_or(Error::DepositNotFound)?;
+        Ok(deposit.regenerated(env.ledger().timestamp()))
+    }
+
+    // Get the stars placed by hand; generated stars are listed by sector
+    pub fn get_all_stars(env: Env) -> Vec<Symbol> {
+        storage::read_moved(&env, &DataKey::StarList).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Helper functions
+    // Store a star that does not exist yet
+    fn store_new_star(env: &Env, star: &Star) -> Result<(), Error> {
+        let key = DataKey::Star(star.name.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::StarExists);
+        }
+
+        storage::write_persistent(env, &key, star);
+        events::star_created(env, star);
+        Ok(())
+    }
+
+    fn sector_index(env: &Env, sector: u32) -> Vec<StarPosition> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SectorStars(sector))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    // The deposit of `resource_name` at an existing star, created empty and
+    // listed on the star the first time it is configured
+    fn open_deposit(env: &Env, star_name: &Symbol, resource_name: &Symbol) -> Result<Deposit, Error> {
+        let mut star = migration::load_star(env, star_name).ok_or(Error::StarNotFound)?;
+
+        match Self::settle_deposit(env, star_name, resource_name) {
+            Some(deposit) => Ok(deposit),
+            None => {
+                star.resources.push_back(resource_name.clone());
+                storage::write_persistent(env, &DataKey::Star(star_name.clone()), &star);
+                Ok(Deposit {
+                    amount: 0,
+                    capacity: 0,
+                    regen_rate: 0,
+                    updated_at: env.ledger().timestamp(),
+                })
+            }
+        }
+    }
+
+    // Apply and store regeneration, announcing any growth
+    fn settle_deposit(env: &Env, star_name: &Symbol, resource_name: &Symbol) -> Option<Deposit> {
+        let stored = migration::load_deposit(env, star_name, resource_name)?;
+        let deposit = stored.regenerated(env.ledger().timestamp());
+        if deposit != stored {
+            Self::write_deposit(env, star_name, resource_name, &deposit);
+        }
+        if deposit.amount > stored.amount {
+            events::deposit_changed(env, star_name, resource_name, deposit.amount);
+        }
+        Some(deposit)
+    }
+
+    fn write_deposit(env: &Env, star_name: &Symbol, resource_name: &Symbol, deposit: &Deposit) {
+        let key = DataKey::Deposit(star_name.clone(), resource_name.clone());
+        storage::write_persistent(env, &key, deposit);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ResourceAmount(star_name.clone(), resource_name.clone()));
+    }
+}
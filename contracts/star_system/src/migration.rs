@@ -0,0 +1,88 @@
+use game_common::{storage, upgrade};
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, TryFromVal, Val, Vec};
+
+use crate::{DataKey, Deposit, SpectralClass, Star};
+
+// Star layout written before version 4, placed by its distance from
+// Alpha Centauri alone
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StarV3 {
+    pub name: Symbol,
+    pub resources: Vec<Symbol>,
+    pub distance: i128,
+    pub discovered: bool,
+}
+
+impl From<StarV3> for StarV4 {
+    // Older stars go on the x axis at their old distance
+    fn from(star: StarV3) -> Self {
+        StarV4 {
+            name: star.name,
+            resources: star.resources,
+            x: star.distance.clamp(i32::MIN as i128, i32::MAX as i128) as i32,
+            y: 0,
+            z: 0,
+            discovered: star.discovered,
+        }
+    }
+}
+
+// Star layout written before version 5, without a spectral class
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StarV4 {
+    pub name: Symbol,
+    pub resources: Vec<Symbol>,
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub discovered: bool,
+}
+
+impl From<StarV4> for Star {
+    // Stars from before classes existed are taken to be sun-like
+    fn from(star: StarV4) -> Self {
+        Star {
+            name: star.name,
+            resources: star.resources,
+            x: star.x,
+            y: star.y,
+            z: star.z,
+            class: SpectralClass::G,
+            discovered: star.discovered,
+        }
+    }
+}
+
+// Read a star in whichever layout it was written
+pub fn load_star(env: &Env, star_name: &Symbol) -> Option<Star> {
+    let record: Val = storage::read_persistent(env, &DataKey::Star(star_name.clone()))?;
+
+    if upgrade::has_field(env, &record, symbol_short!("class")) {
+        Some(Star::try_from_val(env, &record).unwrap())
+    } else if upgrade::has_field(env, &record, symbol_short!("x")) {
+        Some(StarV4::try_from_val(env, &record).unwrap().into())
+    } else {
+        Some(StarV4::from(StarV3::try_from_val(env, &record).unwrap()).into())
+    }
+}
+
+// Read a deposit in whichever layout it was written. Before version 3 a
+// deposit was a bare amount, which becomes a full deposit that does not
+// regenerate.
+pub fn load_deposit(env: &Env, star_name: &Symbol, resource_name: &Symbol) -> Option<Deposit> {
+    let key = DataKey::Deposit(star_name.clone(), resource_name.clone());
+    if let Some(deposit) = env.storage().persistent().get(&key) {
+        return Some(deposit);
+    }
+
+    let legacy = DataKey::ResourceAmount(star_name.clone(), resource_name.clone());
+    let amount: i128 = env.storage().persistent().get(&legacy)?;
+    Some(Deposit {
+        amount,
+        capacity: amount,
+        regen_rate: 0,
+        updated_at: env.ledger().timestamp(),
+    })
+}
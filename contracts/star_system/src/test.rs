@@ -0,0 +1,856 @@
+#![cfg(test)]
+
+use super::*;
+use game_common::events::{CREATED, DEPLETED, DISCOVERY, EVENT_VERSION, EXTRACTED, GALAXY, REPLENISH};
+use game_common::interfaces::Scanner;
+use game_common::registry::Role;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal,
+};
+
+fn setup(env: &Env) -> (Address, StarSystemClient<'_>) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, StarSystem);
+    let client = StarSystemClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    // Resource symbols are checked against the registry
+    let registry = env.register_contract(None, ::registry::Registry);
+    ::registry::RegistryClient::new(env, &registry).initialize(&admin);
+    client.set_registry(&registry);
+    (admin, client)
+}
+
+// Stands in for the Shipyard, reporting whatever scanners a test parks
+#[contract]
+struct MockShipyard;
+
+#[contractimpl]
+impl MockShipyard {
+    pub fn park(env: Env, ship_id: u64, scanner: Scanner) {
+        env.storage().instance().set(&ship_id, &scanner);
+    }
+
+    pub fn get_scanner(env: Env, ship_id: u64) -> Scanner {
+        env.storage().instance().get(&ship_id).unwrap()
+    }
+}
+
+fn mock_shipyard<'a>(env: &Env, client: &StarSystemClient) -> MockShipyardClient<'a> {
+    let shipyard = MockShipyardClient::new(env, &env.register_contract(None, MockShipyard));
+    ::registry::RegistryClient::new(env, &client.get_registry())
+        .set_contract(&Role::Shipyard, &shipyard.address);
+    shipyard
+}
+
+fn docked(owner: &Address, star: &Symbol, scan_range: u64) -> Scanner {
+    Scanner { owner: owner.clone(), star: Some(star.clone()), scan_range }
+}
+
+#[test]
+fn test_initialize_system_twice() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+
+    client.initialize_system(&admin);
+    assert_eq!(client.get_all_stars().len(), 3);
+    assert_eq!(client.try_initialize_system(&admin), Err(Ok(Error::StarExists)));
+}
+
+#[test]
+fn test_distances_between_stars() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let alpha = Symbol::new(&env, "ALPHA_CENTAURI");
+    let proxima = Symbol::new(&env, "PROXIMA_CENTAURI");
+    let barnard = Symbol::new(&env, "BARNARDS_STAR");
+
+    client.initialize_system(&admin);
+    assert_eq!(client.get_distance(&alpha, &proxima), 4);
+    assert_eq!(client.get_distance(&alpha, &barnard), 6);
+    assert_eq!(client.get_distance(&barnard, &proxima), 6);
+    assert_eq!(client.get_distance(&proxima, &proxima), 0);
+
+    assert_eq!(client.get_stars_within(&alpha, &3), Vec::<Symbol>::new(&env));
+    assert_eq!(client.get_stars_within(&alpha, &5), vec![&env, proxima.clone()]);
+    assert_eq!(client.get_stars_within(&proxima, &6), vec![&env, alpha.clone(), barnard.clone()]);
+    assert_eq!(
+        client.try_get_distance(&alpha, &Symbol::new(&env, "VEGA")),
+        Err(Ok(Error::StarNotFound))
+    );
+}
+
+fn galaxy_config(seed: u64) -> GalaxyConfig {
+    GalaxyConfig { seed, sectors: 5, star_density: 4, richness: 100 }
+}
+
+// Every generated star with its deposits
+fn generated_galaxy(env: &Env, client: &StarSystemClient) -> Vec<(Star, Vec<Deposit>)> {
+    let mut stars = Vec::new(env);
+    let mut names = Vec::new(env);
+    for sector in 0..client.get_galaxy().config.sectors {
+        names.append(&client.get_sector_stars(&sector));
+    }
+    for name in names.iter() {
+        let star = client.get_star_info(&name);
+        let mut deposits = Vec::new(env);
+        for resource in star.resources.iter() {
+            deposits.push_back(client.get_deposit(&name, &resource));
+        }
+        stars.push_back((star, deposits));
+    }
+    stars
+}
+
+#[test]
+fn test_galaxy_is_generated_in_batches() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    assert_eq!(client.try_generate_sectors(&1), Err(Ok(Error::GalaxyNotStarted)));
+    for config in [
+        GalaxyConfig { sectors: 0, ..galaxy_config(7) },
+        GalaxyConfig { sectors: MAX_SECTORS + 1, ..galaxy_config(7) },
+        GalaxyConfig { sectors: u32::MAX, ..galaxy_config(7) },
+        GalaxyConfig { star_density: 0, ..galaxy_config(7) },
+        GalaxyConfig { star_density: MAX_STARS_PER_SECTOR + 1, ..galaxy_config(7) },
+        GalaxyConfig { richness: 0, ..galaxy_config(7) },
+    ] {
+        assert_eq!(client.try_start_galaxy(&config), Err(Ok(Error::InvalidGalaxy)));
+    }
+
+    client.start_galaxy(&galaxy_config(7));
+    let all = env.events().all();
+    assert_eq!(
+        all.slice(all.len() - 1..),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (GALAXY, CREATED, EVENT_VERSION).into_val(&env),
+                events::GalaxyCreated { config: galaxy_config(7) }.into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(client.try_start_galaxy(&galaxy_config(8)), Err(Ok(Error::GalaxyExists)));
+
+    // Batches are capped, however many sectors are asked for
+    assert_eq!(client.generate_sectors(&10), 3);
+    assert_eq!(client.get_galaxy().next_sector, 2);
+    assert_eq!(client.generate_sectors(&1), 2);
+    assert_eq!(client.generate_sectors(&10), 0);
+    assert_eq!(client.generate_sectors(&10), 0);
+    assert_eq!(client.get_galaxy().next_sector, 5);
+
+    // Generated stars are indexed by sector, not added to the star list
+    assert_eq!(client.get_all_stars().len(), 0);
+    let stars = generated_galaxy(&env, &client);
+    assert!(stars.len() >= 5 && stars.len() <= 5 * 4);
+    assert_eq!(client.get_sector_stars(&0).get(0).unwrap(), Symbol::new(&env, "S0_0"));
+    assert_eq!(client.get_sector_stars(&5).len(), 0);
+
+    // Sectors sit on a 2x2x2 grid, and deposits are full and sized by richness
+    for (star, deposits) in generated_galaxy(&env, &client).iter() {
+        for coordinate in [star.x, star.y, star.z] {
+            assert!((0..40).contains(&coordinate));
+        }
+        assert!(!star.discovered);
+        for deposit in deposits.iter() {
+            assert_eq!(deposit.amount, deposit.capacity);
+            assert_eq!(deposit.capacity % 100, 0);
+            assert!(deposit.capacity >= 100 && deposit.capacity <= 1_000);
+            assert_eq!(deposit.regen_rate, deposit.capacity / 100);
+        }
+    }
+}
+
+#[test]
+fn test_galaxy_generation_is_seeded() {
+    let generate = |env: &Env, seed: u64, batch: u32| {
+        let (_, client) = setup(env);
+        client.start_galaxy(&galaxy_config(seed));
+        while client.generate_sectors(&batch) > 0 {}
+        generated_galaxy(env, &client)
+    };
+
+    // The same seed yields the same galaxy, however it is batched
+    let env = Env::default();
+    let galaxy = generate(&env, 7, 1);
+    assert_eq!(galaxy, generate(&env, 7, 2));
+    assert_ne!(galaxy, generate(&env, 8, 2));
+}
+
+#[test]
+fn test_nearby_search_reads_only_sectors_in_reach() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    client.initialize_system(&admin);
+    client.start_galaxy(&GalaxyConfig { seed: 3, sectors: 8, star_density: 3, richness: 10 });
+    while client.generate_sectors(&2) > 0 {}
+
+    // Every star, placed by hand or generated, to check the search against
+    let mut stars = Vec::new(&env);
+    for name in client.get_all_stars().iter() {
+        stars.push_back(client.get_star_info(&name));
+    }
+    for sector in 0..8 {
+        for name in client.get_sector_stars(&sector).iter() {
+            stars.push_back(client.get_star_info(&name));
+        }
+    }
+
+    for center in stars.iter() {
+        for radius in [0, 7, MAX_SEARCH_RADIUS] {
+            let nearby = client.get_stars_within(&center.name, &radius);
+            let mut expected = 0;
+            for star in stars.iter() {
+                if star.name != center.name && center.distance_to(&star) <= radius {
+                    assert!(nearby.contains(&star.name));
+                    expected += 1;
+                }
+            }
+            assert_eq!(nearby.len(), expected);
+        }
+    }
+
+    let alpha = Symbol::new(&env, "ALPHA_CENTAURI");
+    assert_eq!(
+        client.try_get_stars_within(&alpha, &(MAX_SEARCH_RADIUS + 1)),
+        Err(Ok(Error::SearchTooWide))
+    );
+}
+
+#[test]
+fn test_galaxy_generation_requires_admin() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+
+    env.set_auths(&[]);
+    assert!(client.try_start_galaxy(&galaxy_config(7)).is_err());
+    env.mock_all_auths();
+    client.start_galaxy(&galaxy_config(7));
+
+    env.set_auths(&[]);
+    assert!(client.try_generate_sectors(&1).is_err());
+    assert_eq!(client.get_all_stars().len(), 0);
+}
+
+#[test]
+fn test_discover_star() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let shipyard = mock_shipyard(&env, &client);
+    let (first, second) = (Address::generate(&env), Address::generate(&env));
+    let alpha = Symbol::new(&env, "ALPHA_CENTAURI");
+    let star = Symbol::new(&env, "PROXIMA_CENTAURI");
+
+    client.initialize_system(&admin);
+    shipyard.park(&1, &docked(&first, &alpha, 5));
+    shipyard.park(&2, &docked(&second, &alpha, 5));
+    assert_eq!(client.try_get_first_discovery(&star), Err(Ok(Error::NotDiscovered)));
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_000);
+    assert!(client.discover_star(&first, &1, &star));
+    assert!(client.get_star_info(&star).discovered);
+    assert_eq!(
+        client.try_discover_star(&first, &1, &star),
+        Err(Ok(Error::AlreadyDiscovered))
+    );
+
+    // Others can still discover it, but the first discoverer keeps the credit
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_000);
+    assert!(!client.discover_star(&second, &2, &star));
+    assert!(client.discover_star(&second, &2, &alpha));
+    assert_eq!(
+        client.get_first_discovery(&star),
+        Discovery { player: first.clone(), discovered_at: 1_000 }
+    );
+    assert_eq!(client.get_discovered_by(&first, &0), vec![&env, star.clone()]);
+    assert_eq!(client.get_discovered_by(&second, &0), vec![&env, star.clone(), alpha.clone()]);
+    assert_eq!(client.get_discovery_count(&second), 2);
+    assert!(client.has_discovered(&first, &star));
+    assert!(!client.has_discovered(&first, &alpha));
+}
+
+#[test]
+fn test_discoveries_are_listed_in_pages() {
+    let env = Env::default();
+    let (_, client) = setup(&env);
+    let shipyard = mock_shipyard(&env, &client);
+    let player = Address::generate(&env);
+    let home = Symbol::new(&env, "S0_0");
+
+    // A sector's worth of stars more than a page holds, all in one spot
+    let mut names = Vec::new(&env);
+    for index in 0..=DISCOVERY_PAGE_SIZE {
+        names.push_back(galaxy::star_name(&env, 0, index));
+    }
+    env.as_contract(&client.address, || {
+        for name in names.iter() {
+            let star = Star {
+                name: name.clone(),
+                resources: Vec::new(&env),
+                x: 0,
+                y: 0,
+                z: 0,
+                class: SpectralClass::M,
+                discovered: false,
+            };
+            env.storage().persistent().set(&DataKey::Star(name), &star);
+        }
+    });
+    shipyard.park(&1, &docked(&player, &home, 0));
+
+    for name in names.iter() {
+        client.discover_star(&player, &1, &name);
+    }
+    assert_eq!(client.get_discovery_count(&player), DISCOVERY_PAGE_SIZE + 1);
+    assert_eq!(client.get_discovered_by(&player, &0), names.slice(..DISCOVERY_PAGE_SIZE));
+    assert_eq!(
+        client.get_discovered_by(&player, &1),
+        vec![&env, names.last().unwrap()]
+    );
+    assert_eq!(client.get_discovered_by(&player, &2).len(), 0);
+}
+
+#[test]
+fn test_discovery_needs_a_ship_in_range() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let player = Address::generate(&env);
+    let other = Address::generate(&env);
+    let alpha = Symbol::new(&env, "ALPHA_CENTAURI");
+    let barnard = Symbol::new(&env, "BARNARDS_STAR");
+
+    client.initialize_system(&admin);
+    assert_eq!(
+        client.try_discover_star(&player, &1, &barnard),
+        Err(Ok(Error::NotRegistered))
+    );
+
+    let shipyard = mock_shipyard(&env, &client);
+    shipyard.park(&1, &docked(&player, &alpha, 5));
+    shipyard.park(&2, &docked(&other, &alpha, 10));
+    shipyard.park(&3, &Scanner { owner: player.clone(), star: None, scan_range: 10 });
+    shipyard.park(&4, &docked(&player, &alpha, 6));
+
+    // Barnard's Star is 6 from Alpha Centauri
+    assert_eq!(
+        client.try_discover_star(&player, &1, &barnard),
+        Err(Ok(Error::OutOfScanRange))
+    );
+    assert_eq!(
+        client.try_discover_star(&player, &2, &barnard),
+        Err(Ok(Error::NotShipOwner))
+    );
+    assert_eq!(
+        client.try_discover_star(&player, &3, &barnard),
+        Err(Ok(Error::ShipInTransit))
+    );
+    assert_eq!(
+        client.try_discover_star(&player, &5, &barnard),
+        Err(Ok(Error::ShipNotFound))
+    );
+    assert_eq!(client.get_discovered_by(&player, &0), Vec::<Symbol>::new(&env));
+    assert_eq!(client.get_discovery_count(&player), 0);
+    assert!(!client.get_star_info(&barnard).discovered);
+
+    assert!(client.discover_star(&player, &4, &barnard));
+}
+
+#[test]
+fn test_unknown_star() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let player = Address::generate(&env);
+    let star = symbol_short!("NOWHERE");
+
+    assert_eq!(client.try_get_star_info(&star), Err(Ok(Error::StarNotFound)));
+    assert_eq!(
+        client.try_discover_star(&player, &1, &star),
+        Err(Ok(Error::StarNotFound))
+    );
+    assert_eq!(
+        client.try_add_resource(&admin, &star, &symbol_short!("IRON"), &100),
+        Err(Ok(Error::StarNotFound))
+    );
+}
+
+#[test]
+fn test_discover_star_requires_auth() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let shipyard = mock_shipyard(&env, &client);
+    let player = Address::generate(&env);
+    let star = Symbol::new(&env, "ALPHA_CENTAURI");
+
+    client.initialize_system(&admin);
+    shipyard.park(&1, &docked(&player, &star, 5));
+
+    env.set_auths(&[]);
+    assert!(client.try_discover_star(&player, &1, &star).is_err());
+    assert!(!client.get_star_info(&star).discovered);
+}
+
+#[test]
+fn test_world_building_requires_admin_or_operator() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let designer = Address::generate(&env);
+    let star = Symbol::new(&env, "BARNARDS_STAR");
+    let iron = symbol_short!("IRON");
+
+    assert_eq!(
+        client.try_initialize_system(&designer),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.set_operator(&designer, &true);
+    assert!(client.is_operator(&designer));
+    client.initialize_system(&designer);
+    client.add_resource(&designer, &star, &iron, &500);
+    assert_eq!(client.get_resource_amount(&star, &iron), 500);
+
+    // Operators cannot manage roles themselves
+    env.set_auths(&[]);
+    assert!(client.try_set_operator(&designer, &false).is_err());
+    env.mock_all_auths();
+
+    client.set_operator(&designer, &false);
+    assert_eq!(
+        client.try_add_resource(&designer, &star, &iron, &1_000_000),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_deposits_need_known_resources() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let star = Symbol::new(&env, "BARNARDS_STAR");
+    let typo = symbol_short!("IRNO");
+
+    client.initialize_system(&admin);
+    assert_eq!(
+        client.try_add_resource(&admin, &star, &typo, &100),
+        Err(Ok(Error::UnknownResource))
+    );
+    assert_eq!(
+        client.try_configure_deposit(&admin, &star, &typo, &100, &1),
+        Err(Ok(Error::UnknownResource))
+    );
+    assert_eq!(client.get_resource_amount(&star, &typo), 0);
+}
+
+#[test]
+fn test_initialize_and_transfer_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, StarSystem);
+    let client = StarSystemClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    assert_eq!(
+        client.try_initialize_system(&admin),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&new_admin),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+
+    client.transfer_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(
+        client.try_initialize_system(&admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.initialize_system(&new_admin);
+}
+
+#[test]
+fn test_migrate_star_list_to_instance() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+
+    client.initialize_system(&admin);
+
+    // Version 1 kept the star list in persistent storage
+    env.as_contract(&client.address, || {
+        let stars: Vec<Symbol> = env.storage().instance().get(&DataKey::StarList).unwrap();
+        env.storage().instance().remove(&DataKey::StarList);
+        env.storage().persistent().set(&DataKey::StarList, &stars);
+        game_common::upgrade::set_schema_version(&env, 1);
+    });
+
+    client.migrate();
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    assert_eq!(client.get_all_stars().len(), 3);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&DataKey::StarList));
+    });
+}
+
+#[test]
+fn test_migrate_resource_amounts_to_deposits() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let star = Symbol::new(&env, "BARNARDS_STAR");
+    let iron = symbol_short!("IRON");
+
+    client.initialize_system(&admin);
+
+    // Version 2 stored a bare amount per resource
+    env.as_contract(&client.address, || {
+        let mut info: Star = env.storage().persistent().get(&DataKey::Star(star.clone())).unwrap();
+        info.resources.push_back(iron.clone());
+        env.storage().persistent().set(&DataKey::Star(star.clone()), &info);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ResourceAmount(star.clone(), iron.clone()), &400i128);
+        game_common::upgrade::set_schema_version(&env, 2);
+    });
+
+    // Readable before the migration, as a full deposit that does not refill
+    assert_eq!(client.get_resource_amount(&star, &iron), 400);
+
+    client.migrate();
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    assert_eq!(
+        client.get_deposit(&star, &iron),
+        Deposit { amount: 400, capacity: 400, regen_rate: 0, updated_at: 0 }
+    );
+    assert_eq!(client.get_star_info(&star).resources, vec![&env, iron.clone()]);
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::ResourceAmount(star.clone(), iron.clone())));
+    });
+}
+
+#[test]
+fn test_migrate_stars_onto_an_axis() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let alpha = Symbol::new(&env, "ALPHA_CENTAURI");
+    let barnard = Symbol::new(&env, "BARNARDS_STAR");
+
+    client.initialize_system(&admin);
+
+    // Version 3 kept only each star's distance from Alpha Centauri
+    env.as_contract(&client.address, || {
+        for (name, distance) in [(&alpha, 0i128), (&barnard, 6)] {
+            let legacy = migration::StarV3 {
+                name: name.clone(),
+                resources: Vec::new(&env),
+                distance,
+                discovered: false,
+            };
+            env.storage().persistent().set(&DataKey::Star(name.clone()), &legacy);
+        }
+        game_common::upgrade::set_schema_version(&env, 3);
+    });
+
+    // Readable before the migration, placed on the x axis
+    let star = client.get_star_info(&barnard);
+    assert_eq!((star.x, star.y, star.z), (6, 0, 0));
+    assert_eq!(star.class, SpectralClass::G);
+    assert_eq!(client.get_distance(&alpha, &barnard), 6);
+
+    client.migrate();
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    env.as_contract(&client.address, || {
+        let stored: Star = env.storage().persistent().get(&DataKey::Star(barnard.clone())).unwrap();
+        assert_eq!((stored.x, stored.y, stored.z), (6, 0, 0));
+    });
+}
+
+#[test]
+fn test_deposits_regenerate_up_to_capacity() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let miner = Address::generate(&env);
+    let star = Symbol::new(&env, "BARNARDS_STAR");
+    let iron = symbol_short!("IRON");
+    let advance = |seconds: u64| env.ledger().with_mut(|ledger| ledger.timestamp += seconds);
+
+    client.initialize_system(&admin);
+    client.set_operator(&miner, &true);
+    assert_eq!(client.try_get_deposit(&star, &iron), Err(Ok(Error::DepositNotFound)));
+    assert_eq!(
+        client.try_configure_deposit(&admin, &star, &iron, &-1, &10),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    client.configure_deposit(&admin, &star, &iron, &1_000, &100);
+    assert_eq!(client.get_resource_amount(&star, &iron), 0);
+    assert_eq!(client.get_star_info(&star).resources, vec![&env, iron.clone()]);
+
+    // Stock is computed from the ledger time, whole units at a time
+    advance(REGEN_PERIOD);
+    assert_eq!(client.get_resource_amount(&star, &iron), 100);
+    advance(REGEN_PERIOD / 2 + 18);
+    assert_eq!(client.get_resource_amount(&star, &iron), 150);
+
+    // Extracting stores the regenerated stock first; partial units carry over
+    assert_eq!(client.extract(&miner, &star, &iron, &150), 150);
+    assert_eq!(client.get_resource_amount(&star, &iron), 0);
+    advance(18);
+    assert_eq!(client.get_resource_amount(&star, &iron), 1);
+
+    // Never beyond capacity
+    advance(100 * REGEN_PERIOD);
+    assert_eq!(client.get_resource_amount(&star, &iron), 1_000);
+    assert_eq!(client.extract(&miner, &star, &iron, &2_000), 1_000);
+    advance(REGEN_PERIOD);
+    assert_eq!(client.get_resource_amount(&star, &iron), 100);
+
+    // Shrinking capacity trims the stock
+    client.configure_deposit(&admin, &star, &iron, &60, &0);
+    advance(REGEN_PERIOD);
+    assert_eq!(client.get_deposit(&star, &iron).amount, 60);
+    assert_eq!(client.get_deposit(&star, &iron).capacity, 60);
+}
+
+#[test]
+fn test_deposit_lifecycle_events() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let miner = Address::generate(&env);
+    let star = Symbol::new(&env, "BARNARDS_STAR");
+    let iron = symbol_short!("IRON");
+    let deposit_topics =
+        |action| (game_common::events::DEPOSIT, action, EVENT_VERSION).into_val(&env);
+    let last_events = |count: u32| {
+        let all = env.events().all();
+        all.slice(all.len() - count..)
+    };
+
+    client.initialize_system(&admin);
+    client.set_operator(&miner, &true);
+    client.add_resource(&admin, &star, &iron, &200);
+    client.configure_deposit(&admin, &star, &iron, &200, &50);
+    assert_eq!(
+        last_events(1),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                deposit_topics(game_common::events::SET),
+                events::DepositConfigured {
+                    star: star.clone(),
+                    resource: iron.clone(),
+                    deposit: client.get_deposit(&star, &iron),
+                }
+                .into_val(&env),
+            ),
+        ]
+    );
+
+    client.extract(&miner, &star, &iron, &200);
+    assert_eq!(
+        last_events(1),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                deposit_topics(DEPLETED),
+                events::ResourceDepleted { star: star.clone(), resource: iron.clone() }
+                    .into_val(&env),
+            ),
+        ]
+    );
+
+    // Nothing is announced until the deposit is touched again
+    env.ledger().with_mut(|ledger| ledger.timestamp += 2 * REGEN_PERIOD);
+    assert_eq!(client.refresh_deposit(&star, &iron), 100);
+    assert_eq!(
+        last_events(1),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                deposit_topics(REPLENISH),
+                events::ResourceReplenished { star: star.clone(), resource: iron.clone(), amount: 100 }
+                    .into_val(&env),
+            ),
+        ]
+    );
+
+    // Extraction after regeneration announces both changes
+    env.ledger().with_mut(|ledger| ledger.timestamp += REGEN_PERIOD);
+    client.extract(&miner, &star, &iron, &30);
+    assert_eq!(
+        last_events(2),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                deposit_topics(REPLENISH),
+                events::ResourceReplenished { star: star.clone(), resource: iron.clone(), amount: 150 }
+                    .into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                deposit_topics(EXTRACTED),
+                events::ResourceExtracted { star: star.clone(), resource: iron.clone(), amount: 120 }
+                    .into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(
+        client.try_refresh_deposit(&star, &symbol_short!("GOLD")),
+        Err(Ok(Error::DepositNotFound))
+    );
+}
+
+
+#[test]
+fn test_star_events() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let shipyard = mock_shipyard(&env, &client);
+    let player = Address::generate(&env);
+    let star = Symbol::new(&env, "BARNARDS_STAR");
+    let iron = symbol_short!("IRON");
+    let topics = |action| (game_common::events::STAR, action, EVENT_VERSION).into_val(&env);
+    let deposit_topics =
+        |action| (game_common::events::DEPOSIT, action, EVENT_VERSION).into_val(&env);
+
+    // Events accumulate across invocations; each call below adds one
+    let last_event = || {
+        let all = env.events().all();
+        all.slice(all.len() - 1..)
+    };
+
+    client.initialize_system(&admin);
+    assert_eq!(
+        last_event(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                topics(CREATED),
+                events::StarCreated { star: client.get_star_info(&star) }.into_val(&env),
+            ),
+        ]
+    );
+
+    shipyard.park(&1, &docked(&player, &star, 5));
+    client.discover_star(&player, &1, &star);
+    assert_eq!(
+        last_event(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                topics(DISCOVERY),
+                events::StarDiscovered { player, star: star.clone(), first: true }.into_val(&env),
+            ),
+        ]
+    );
+
+    client.add_resource(&admin, &star, &iron, &250);
+    assert_eq!(
+        last_event(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                deposit_topics(REPLENISH),
+                events::ResourceReplenished { star: star.clone(), resource: iron.clone(), amount: 250 }
+                    .into_val(&env),
+            ),
+        ]
+    );
+
+    client.add_resource(&admin, &star, &iron, &0);
+    assert_eq!(
+        last_event(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                deposit_topics(DEPLETED),
+                events::ResourceDepleted { star, resource: iron }.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_extract_draws_down_deposit() {
+    let env = Env::default();
+    let (admin, client) = setup(&env);
+    let miner = Address::generate(&env);
+    let star = Symbol::new(&env, "BARNARDS_STAR");
+    let iron = symbol_short!("IRON");
+    let deposit_topics =
+        |action| (game_common::events::DEPOSIT, action, EVENT_VERSION).into_val(&env);
+    let last_event = || {
+        let all = env.events().all();
+        all.slice(all.len() - 1..)
+    };
+
+    client.initialize_system(&admin);
+    client.add_resource(&admin, &star, &iron, &250);
+    assert_eq!(
+        client.try_extract(&miner, &star, &iron, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_operator(&miner, &true);
+
+    assert_eq!(client.extract(&miner, &star, &iron, &100), 100);
+    assert_eq!(client.get_resource_amount(&star, &iron), 150);
+    assert_eq!(
+        last_event(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                deposit_topics(EXTRACTED),
+                events::ResourceExtracted { star: star.clone(), resource: iron.clone(), amount: 150 }
+                    .into_val(&env),
+            ),
+        ]
+    );
+
+    // Only what is left can be taken, and an empty deposit yields nothing
+    assert_eq!(client.extract(&miner, &star, &iron, &400), 150);
+    assert_eq!(
+        last_event(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                deposit_topics(DEPLETED),
+                events::ResourceDepleted { star: star.clone(), resource: iron.clone() }
+                    .into_val(&env),
+            ),
+        ]
+    );
+    assert_eq!(
+        client.try_extract(&miner, &star, &iron, &1),
+        Err(Ok(Error::DepositEmpty))
+    );
+    assert_eq!(
+        client.try_extract(&miner, &star, &symbol_short!("GOLD"), &1),
+        Err(Ok(Error::DepositEmpty))
+    );
+    assert_eq!(
+        client.try_extract(&miner, &star, &iron, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_extract(&miner, &symbol_short!("NOWHERE"), &iron, &1),
+        Err(Ok(Error::StarNotFound))
+    );
+}
@@ -0,0 +1,39 @@
+use game_common::events::{self, ACCEPTED, CANCELLED, CREATED, OFFER};
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::TradeOffer;
+
+// (OFFER, CREATED)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferCreated {
+    pub offer: TradeOffer,
+}
+
+// (OFFER, ACCEPTED): `buyer` took `offer_id`, which is no longer active
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferAccepted {
+    pub buyer: Address,
+    pub offer_id: u32,
+}
+
+// (OFFER, CANCELLED): `seller` withdrew `offer_id`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferCancelled {
+    pub seller: Address,
+    pub offer_id: u32,
+}
+
+pub fn offer_created(env: &Env, offer: &TradeOffer) {
+    events::publish(env, OFFER, CREATED, OfferCreated { offer: offer.clone() });
+}
+
+pub fn offer_accepted(env: &Env, buyer: &Address, offer_id: u32) {
+    events::publish(env, OFFER, ACCEPTED, OfferAccepted { buyer: buyer.clone(), offer_id });
+}
+
+pub fn offer_cancelled(env: &Env, seller: &Address, offer_id: u32) {
+    events::publish(env, OFFER, CANCELLED, OfferCancelled { seller: seller.clone(), offer_id });
+}
@@ -0,0 +1,283 @@
+#![no_std]
+mod error;
+pub mod events;
+mod migration;
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+
+use game_common::interfaces::Trade;
+use game_common::{access, registry, storage, upgrade};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    Address, BytesN, Env, Symbol, Vec,
+};
+
+// Layout version of the records this code writes
+pub const SCHEMA_VERSION: u32 = 3;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Offer(u32),
+    OfferCounter,
+    PlayerOffers(Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradeOffer {
+    pub id: u32,
+    pub seller: Address,
+    pub sell_resource: Symbol,
+    pub sell_amount: i128,
+    pub buy_resource: Symbol,
+    pub buy_amount: i128,
+    pub active: bool,
+    pub created_at: u64,
+}
+
+#[contract]
+pub struct Trading;
+
+#[contractimpl]
+impl Trading {
+    // Admin
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        access::initialize(&env, &admin)?;
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+        storage::extend_instance(&env);
+        Ok(())
+    }
+
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        access::transfer_admin(&env, &new_admin)?;
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        Ok(access::read_admin(&env)?)
+    }
+
+    pub fn set_operator(env: Env, operator: Address, enabled: bool) -> Result<(), Error> {
+        access::set_operator(&env, &operator, enabled)?;
+        Ok(())
+    }
+
+    pub fn is_operator(env: Env, account: Address) -> bool {
+        access::is_operator(&env, &account)
+    }
+
+    // Upgrades
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        upgrade::upgrade(&env, wasm_hash)?;
+        Ok(())
+    }
+
+    pub fn version(env: Env) -> u32 {
+        upgrade::schema_version(&env)
+    }
+
+    // Registry
+    pub fn set_registry(env: Env, registry: Address) -> Result<(), Error> {
+        registry::set_registry(&env, &registry)?;
+        Ok(())
+    }
+
+    pub fn get_registry(env: Env) -> Result<Address, Error> {
+        Ok(registry::read_registry(&env)?)
+    }
+
+    // Rewrite up to `limit` offer records, starting at `start_id`, into the
+    // current layout. Returns the number of offers rewritten.
+    pub fn migrate(env: Env, start_id: u32, limit: u32) -> Result<u32, Error> {
+        access::require_admin(&env)?;
+        storage::extend_instance(&env);
+
+        // Version 3 keeps the offer counter in instance storage
+        storage::move_to_instance::<_, u32>(&env, &DataKey::OfferCounter);
+
+        let counter = Self::get_offer_counter(&env);
+        let last_id = counter.min(start_id.saturating_add(limit).saturating_sub(1));
+        let mut migrated = 0;
+        for id in start_id.max(1)..=last_id {
+            if let Some(offer) = migration::load_offer(&env, id) {
+                storage::write_persistent(&env, &DataKey::Offer(id), &offer);
+                migrated += 1;
+            }
+        }
+        upgrade::set_schema_version(&env, SCHEMA_VERSION);
+
+        Ok(migrated)
+    }
+
+    // Create a new trade offer from the seller's docked ship `ship_id`
+    pub fn create_offer(
+        env: Env,
+        seller: Address,
+        ship_id: u64,
+        sell_resource: Symbol,
+        sell_amount: i128,
+        buy_resource: Symbol,
+        buy_amount: i128
+    ) -> Result<u32, Error> {
+        seller.require_auth();
+        storage::extend_instance(&env);
+
+        // Validate amounts
+        if sell_amount <= 0 || buy_amount <= 0 {
+            return Err(Error::InvalidAmounts);
+        }
+        registry::tradable_resource(&env, &sell_resource)?;
+        registry::tradable_resource(&env, &buy_resource)?;
+        Self::require_docked(&env, &seller, ship_id)?;
+
+        // Get and increment offer counter
+        let counter = Self::get_offer_counter(&env);
+        let offer_id = counter + 1;
+        env.storage().instance().set(&DataKey::OfferCounter, &offer_id);
+
+        // Create offer
+        let offer = TradeOffer {
+            id: offer_id,
+            seller: seller.clone(),
+            sell_resource,
+            sell_amount,
+            buy_resource,
+            buy_amount,
+            active: true,
+            created_at: env.ledger().timestamp(),
+        };
+
+        // Store offer
+        storage::write_persistent(&env, &DataKey::Offer(offer_id), &offer);
+
+        // Add to player's offers
+        let mut player_offers: Vec<u32> =
+            storage::read_persistent(&env, &DataKey::PlayerOffers(seller.clone()))
+                .unwrap_or_else(|| Vec::new(&env));
+        player_offers.push_back(offer_id);
+        storage::write_persistent(&env, &DataKey::PlayerOffers(seller), &player_offers);
+        events::offer_created(&env, &offer);
+
+        Ok(offer_id)
+    }
+
+    // Accept a trade offer from the buyer's docked ship `ship_id`
+    pub fn accept_offer(env: Env, buyer: Address, ship_id: u64, offer_id: u32) -> Result<(), Error> {
+        buyer.require_auth();
+        storage::extend_instance(&env);
+
+        // Get offer
+        let mut offer = migration::load_offer(&env, offer_id).ok_or(Error::OfferNotFound)?;
+
+        // Validate offer
+        if !offer.active {
+            return Err(Error::OfferNotActive);
+        }
+        if offer.seller == buyer {
+            return Err(Error::CannotAcceptOwnOffer);
+        }
+        let sell_amount = u64::try_from(offer.sell_amount).map_err(|_| Error::InvalidAmounts)?;
+        let buy_amount = u64::try_from(offer.buy_amount).map_err(|_| Error::InvalidAmounts)?;
+        Self::require_docked(&env, &buyer, ship_id)?;
+
+        // Both sides must still hold their part of the trade
+        let economy = registry::economy(&env)?;
+        if economy.get_balance(&offer.seller, &offer.sell_resource) < sell_amount
+            || economy.get_balance(&buyer, &offer.buy_resource) < buy_amount
+        {
+            return Err(Error::InsufficientBalance);
+        }
+
+        // Mark offer as inactive
+        offer.active = false;
+        storage::write_persistent(&env, &DataKey::Offer(offer_id), &offer);
+
+        // Swap the resources in the shared ledger
+        let trade = Trade {
+            seller: offer.seller,
+            buyer: buyer.clone(),
+            sell_resource: offer.sell_resource,
+            sell_amount,
+            buy_resource: offer.buy_resource,
+            buy_amount,
+        };
+        economy.settle_trade(&env.current_contract_address(), &trade);
+        events::offer_accepted(&env, &buyer, offer_id);
+
+        Ok(())
+    }
+
+    // Cancel a trade offer
+    pub fn cancel_offer(env: Env, seller: Address, offer_id: u32) -> Result<(), Error> {
+        seller.require_auth();
+        storage::extend_instance(&env);
+
+        // Get offer
+        let mut offer = migration::load_offer(&env, offer_id).ok_or(Error::OfferNotFound)?;
+
+        // Validate seller
+        if offer.seller != seller {
+            return Err(Error::NotOfferCreator);
+        }
+        if !offer.active {
+            return Err(Error::OfferNotActive);
+        }
+
+        // Mark offer as inactive
+        offer.active = false;
+        storage::write_persistent(&env, &DataKey::Offer(offer_id), &offer);
+        events::offer_cancelled(&env, &seller, offer_id);
+
+        Ok(())
+    }
+
+    // Get active offers
+    pub fn get_active_offers(env: Env) -> Vec<TradeOffer> {
+        let counter = Self::get_offer_counter(&env);
+
+        let mut active_offers = Vec::new(&env);
+        for i in 1..=counter {
+            if let Some(offer) = migration::load_offer(&env, i) {
+                if offer.active {
+                    active_offers.push_back(offer);
+                }
+            }
+        }
+
+        active_offers
+    }
+
+    // Get player's offers
+    pub fn get_player_offers(env: Env, player: Address) -> Vec<u32> {
+        storage::read_persistent(&env, &DataKey::PlayerOffers(player)).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    // Get offer details
+    pub fn get_offer_details(env: Env, offer_id: u32) -> Result<TradeOffer, Error> {
+        migration::load_offer(&env, offer_id).ok_or(Error::OfferNotFound)
+    }
+
+    // Helper functions
+    fn get_offer_counter(env: &Env) -> u32 {
+        storage::read_moved::<_, u32>(env, &DataKey::OfferCounter).unwrap_or(0)
+    }
+
+    // Players trade from a ship of theirs docked at a star, never while it
+    // is travelling
+    fn require_docked(env: &Env, player: &Address, ship_id: u64) -> Result<(), Error> {
+        let scanner = match registry::shipyard(env)?.try_get_scanner(&ship_id) {
+            Ok(Ok(scanner)) => scanner,
+            _ => return Err(Error::ShipNotFound),
+        };
+        if scanner.owner != *player {
+            return Err(Error::NotShipOwner);
+        }
+        if scanner.star.is_none() {
+            return Err(Error::ShipInTransit);
+        }
+        Ok(())
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,44 @@
+use game_common::upgrade;
+use soroban_sdk::{contracttype, Address, Env, Symbol, TryFromVal, Val};
+
+use crate::{DataKey, TradeOffer};
+
+// Offer layout written by schema version 1
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TradeOfferV1 {
+    pub id: u32,
+    pub seller: Address,
+    pub sell_resource: Symbol,
+    pub sell_amount: i128,
+    pub buy_resource: Symbol,
+    pub buy_amount: i128,
+    pub active: bool,
+}
+
+impl From<TradeOfferV1> for TradeOffer {
+    fn from(offer: TradeOfferV1) -> Self {
+        TradeOffer {
+            id: offer.id,
+            seller: offer.seller,
+            sell_resource: offer.sell_resource,
+            sell_amount: offer.sell_amount,
+            buy_resource: offer.buy_resource,
+            buy_amount: offer.buy_amount,
+            active: offer.active,
+            // Creation time was not recorded before version 2
+            created_at: 0,
+        }
+    }
+}
+
+// Read an offer record in whichever layout it was written
+pub fn load_offer(env: &Env, id: u32) -> Option<TradeOffer> {
+    let record: Val = env.storage().persistent().get(&DataKey::Offer(id))?;
+
+    if upgrade::has_field(env, &record, Symbol::new(env, "created_at")) {
+        Some(TradeOffer::try_from_val(env, &record).unwrap())
+    } else {
+        Some(TradeOfferV1::try_from_val(env, &record).unwrap().into())
+    }
+}
@@ -0,0 +1,265 @@
+#![cfg(test)]
+
+use super::*;
+use game_common::interfaces::Scanner;
+use game_common::registry::Role;
+use game_common::resources::{Rarity, ResourceInfo};
+use game_common::storage;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    Address, Env, String,
+};
+
+// Stands in for the Shipyard, reporting whatever scanners a test parks
+#[contract]
+struct MockShipyard;
+
+#[contractimpl]
+impl MockShipyard {
+    pub fn park(env: Env, ship_id: u64, scanner: Scanner) {
+        env.storage().instance().set(&ship_id, &scanner);
+    }
+
+    pub fn get_scanner(env: Env, ship_id: u64) -> Scanner {
+        env.storage().instance().get(&ship_id).unwrap()
+    }
+}
+
+struct World<'a> {
+    client: TradingClient<'a>,
+    shipyard: MockShipyardClient<'a>,
+}
+
+// A ship of `owner`'s, docked at a star unless `in_transit`
+fn ship(owner: &Address, in_transit: bool) -> Scanner {
+    Scanner {
+        owner: owner.clone(),
+        star: if in_transit { None } else { Some(symbol_short!("SOL")) },
+        scan_range: 5,
+    }
+}
+
+fn setup(env: &Env) -> World<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, Trading);
+    let client = TradingClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    // Resource symbols are checked against the registry
+    let registry = ::registry::RegistryClient::new(env, &env.register_contract(None, ::registry::Registry));
+    registry.initialize(&admin);
+    client.set_registry(&registry.address);
+
+    // Traders' ships are looked up in the Shipyard
+    let shipyard = MockShipyardClient::new(env, &env.register_contract(None, MockShipyard));
+    registry.set_contract(&Role::Shipyard, &shipyard.address);
+    World { client, shipyard }
+}
+
+#[test]
+fn test_create_offer_invalid_amounts() {
+    let env = Env::default();
+    let World { client, .. } = setup(&env);
+    let seller = Address::generate(&env);
+
+    assert_eq!(
+        client.try_create_offer(&seller, &1, &symbol_short!("IRON"), &0, &symbol_short!("GOLD"), &1),
+        Err(Ok(Error::InvalidAmounts))
+    );
+    assert_eq!(
+        client.try_create_offer(&seller, &1, &symbol_short!("IRON"), &10, &symbol_short!("GOLD"), &-1),
+        Err(Ok(Error::InvalidAmounts))
+    );
+}
+
+#[test]
+fn test_offers_need_tradable_resources() {
+    let env = Env::default();
+    let World { client, .. } = setup(&env);
+    let seller = Address::generate(&env);
+    let relic = symbol_short!("RELIC");
+
+    assert_eq!(
+        client.try_create_offer(&seller, &1, &symbol_short!("IRNO"), &10, &symbol_short!("GOLD"), &1),
+        Err(Ok(Error::UnknownResource))
+    );
+
+    let registry = ::registry::RegistryClient::new(&env, &client.get_registry());
+    registry.set_resource(
+        &relic,
+        &ResourceInfo {
+            name: String::from_str(&env, "Relic"),
+            rarity: Rarity::Exotic,
+            unit_mass: 1,
+            tradable: false,
+        },
+    );
+    assert_eq!(
+        client.try_create_offer(&seller, &1, &symbol_short!("IRON"), &10, &relic, &1),
+        Err(Ok(Error::NotTradable))
+    );
+    assert_eq!(client.get_active_offers().len(), 0);
+}
+
+#[test]
+fn test_accept_offer() {
+    let env = Env::default();
+    let World { client, shipyard } = setup(&env);
+    let seller = Address::generate(&env);
+    shipyard.park(&1, &ship(&seller, false));
+    let buyer = Address::generate(&env);
+    shipyard.park(&2, &ship(&buyer, false));
+
+    let offer_id = client.create_offer(&seller, &1, &symbol_short!("IRON"), &10, &symbol_short!("GOLD"), &1);
+    assert_eq!(
+        client.try_accept_offer(&seller, &1, &offer_id),
+        Err(Ok(Error::CannotAcceptOwnOffer))
+    );
+
+    // The buyer trades from a ship of their own that is docked
+    assert_eq!(
+        client.try_accept_offer(&buyer, &1, &offer_id),
+        Err(Ok(Error::NotShipOwner))
+    );
+    shipyard.park(&3, &ship(&buyer, true));
+    assert_eq!(
+        client.try_accept_offer(&buyer, &3, &offer_id),
+        Err(Ok(Error::ShipInTransit))
+    );
+    assert_eq!(
+        client.try_accept_offer(&buyer, &4, &offer_id),
+        Err(Ok(Error::ShipNotFound))
+    );
+
+    // Settling goes through the Economy, which this registry does not know,
+    // and the offer stays open when it fails
+    assert_eq!(
+        client.try_accept_offer(&buyer, &2, &offer_id),
+        Err(Ok(Error::NotRegistered))
+    );
+    assert!(client.get_offer_details(&offer_id).active);
+
+    client.cancel_offer(&seller, &offer_id);
+    assert_eq!(
+        client.try_accept_offer(&buyer, &2, &offer_id),
+        Err(Ok(Error::OfferNotActive))
+    );
+    assert_eq!(client.try_accept_offer(&buyer, &2, &99), Err(Ok(Error::OfferNotFound)));
+}
+
+#[test]
+fn test_ships_in_transit_cannot_offer() {
+    let env = Env::default();
+    let World { client, shipyard } = setup(&env);
+    let seller = Address::generate(&env);
+    shipyard.park(&1, &ship(&seller, true));
+
+    assert_eq!(
+        client.try_create_offer(&seller, &1, &symbol_short!("IRON"), &10, &symbol_short!("GOLD"), &1),
+        Err(Ok(Error::ShipInTransit))
+    );
+    shipyard.park(&1, &ship(&seller, false));
+    client.create_offer(&seller, &1, &symbol_short!("IRON"), &10, &symbol_short!("GOLD"), &1);
+    assert_eq!(client.get_active_offers().len(), 1);
+}
+
+#[test]
+fn test_reading_player_offers_extends_ttl() {
+    let env = Env::default();
+    let World { client, shipyard } = setup(&env);
+    let seller = Address::generate(&env);
+    shipyard.park(&1, &ship(&seller, false));
+    let key = DataKey::PlayerOffers(seller.clone());
+    let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+
+    client.create_offer(&seller, &1, &symbol_short!("IRON"), &10, &symbol_short!("GOLD"), &1);
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 2 * storage::DAY_IN_LEDGERS);
+    assert_eq!(ttl(), storage::PERSISTENT_BUMP_AMOUNT - 2 * storage::DAY_IN_LEDGERS);
+
+    assert_eq!(client.get_player_offers(&seller).len(), 1);
+    assert_eq!(ttl(), storage::PERSISTENT_BUMP_AMOUNT);
+}
+
+#[test]
+fn test_cancel_offer() {
+    let env = Env::default();
+    let World { client, shipyard } = setup(&env);
+    let seller = Address::generate(&env);
+    shipyard.park(&1, &ship(&seller, false));
+    let other = Address::generate(&env);
+
+    let offer_id = client.create_offer(&seller, &1, &symbol_short!("IRON"), &10, &symbol_short!("GOLD"), &1);
+    assert_eq!(
+        client.try_cancel_offer(&other, &offer_id),
+        Err(Ok(Error::NotOfferCreator))
+    );
+
+    client.cancel_offer(&seller, &offer_id);
+    assert_eq!(client.get_active_offers().len(), 0);
+    assert_eq!(
+        client.try_cancel_offer(&seller, &offer_id),
+        Err(Ok(Error::OfferNotActive))
+    );
+}
+
+#[test]
+fn test_offer_actions_require_auth() {
+    let env = Env::default();
+    let World { client, shipyard } = setup(&env);
+    let seller = Address::generate(&env);
+    shipyard.park(&1, &ship(&seller, false));
+    let buyer = Address::generate(&env);
+    shipyard.park(&2, &ship(&buyer, false));
+
+    let offer_id = client.create_offer(&seller, &1, &symbol_short!("IRON"), &10, &symbol_short!("GOLD"), &1);
+
+    env.set_auths(&[]);
+    assert!(client
+        .try_create_offer(&seller, &1, &symbol_short!("IRON"), &10, &symbol_short!("GOLD"), &1)
+        .is_err());
+    assert!(client.try_accept_offer(&buyer, &2, &offer_id).is_err());
+    assert!(client.try_cancel_offer(&seller, &offer_id).is_err());
+    assert!(client.get_offer_details(&offer_id).active);
+}
+
+#[test]
+fn test_migrate_offers_from_v1() {
+    let env = Env::default();
+    let World { client, shipyard } = setup(&env);
+    let seller = Address::generate(&env);
+    shipyard.park(&1, &ship(&seller, false));
+
+    env.as_contract(&client.address, || {
+        game_common::upgrade::set_schema_version(&env, 1);
+        let legacy = migration::TradeOfferV1 {
+            id: 1,
+            seller: seller.clone(),
+            sell_resource: symbol_short!("IRON"),
+            sell_amount: 10,
+            buy_resource: symbol_short!("GOLD"),
+            buy_amount: 1,
+            active: true,
+        };
+        env.storage().persistent().set(&DataKey::Offer(1), &legacy);
+        env.storage().persistent().set(&DataKey::OfferCounter, &1u32);
+    });
+
+    // Offers and the counter stay visible before the migration runs, so new
+    // offers continue the id sequence
+    assert_eq!(client.get_active_offers().len(), 1);
+    let offer_id = client.create_offer(&seller, &1, &symbol_short!("WATER"), &5, &symbol_short!("GOLD"), &1);
+    assert_eq!(offer_id, 2);
+    assert_eq!(client.get_offer_details(&1).sell_amount, 10);
+
+    assert_eq!(client.migrate(&1, &10), 2);
+    assert_eq!(client.version(), SCHEMA_VERSION);
+    assert_eq!(client.get_active_offers().len(), 2);
+    assert_eq!(client.get_offer_details(&1).created_at, 0);
+
+    client.cancel_offer(&seller, &1);
+    assert_eq!(client.get_active_offers().len(), 1);
+    let offer_id = client.create_offer(&seller, &1, &symbol_short!("WATER"), &5, &symbol_short!("GOLD"), &1);
+    assert_eq!(offer_id, 3);
+}
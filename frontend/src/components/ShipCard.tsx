@@ -1,98 +1,98 @@
-'use client';
-
-import { useState } from 'react';
-import { config } from '../config';
-
-interface ShipCardProps {
-  name: string;
-  type: string;
-  health: number;
-  cargo: number;
-  resources: { [key: string]: number };
-  onUpgrade?: () => void;
-  onRepair?: () => void;
-}
-
-export default function ShipCard({
-  name,
-  type,
-  health,
-  cargo,
-  resources,
-  onUpgrade,
-  onRepair,
-}: ShipCardProps) {
-  const [isExpanded, setIsExpanded] = useState(false);
-
-  return (
-    <div className="bg-gray-800 rounded-lg overflow-hidden shadow-lg hover:shadow-xl transition-shadow">
-      <div className="p-4">
-        <div className="flex justify-between items-start">
-          <div>
-            <h3 className="text-xl font-bold text-white">{name}</h3>
-            <p className="text-sm text-gray-400">{type}</p>
-          </div>
-          <button
-            onClick={() => setIsExpanded(!isExpanded)}
-            className="text-gray-400 hover:text-white"
-          >
-            {isExpanded ? '▼' : '▶'}
-          </button>
-        </div>
-
-        <div className="mt-4 space-y-2">
-          <div className="flex items-center">
-            <div className="w-24 h-2 bg-gray-700 rounded-full">
-              <div
-                className="h-full bg-blue-600 rounded-full"
-                style={{ width: `${health}%` }}
-              />
-            </div>
-            <span className="ml-2 text-sm text-gray-300">{health}%</span>
-          </div>
-
-          <div className="flex items-center">
-            <div className="w-24 h-2 bg-gray-700 rounded-full">
-              <div
-                className="h-full bg-green-600 rounded-full"
-                style={{ width: `${(cargo / 1000) * 100}%` }}
-              />
-            </div>
-            <span className="ml-2 text-sm text-gray-300">{cargo}/1000</span>
-          </div>
-        </div>
-
-        {isExpanded && (
-          <div className="mt-4 pt-4 border-t border-gray-700">
-            <h4 className="text-sm font-semibold text-gray-300 mb-2">Resources</h4>
-            <div className="grid grid-cols-2 gap-2">
-              {Object.entries(resources).map(([resource, amount]) => (
-                <div key={resource} className="flex justify-between items-center">
-                  <span className="text-sm text-gray-400">
-                    {config.game.resources.find(r => r.id === resource)?.name || resource}
-                  </span>
-                  <span className="text-sm text-white">{amount}</span>
-                </div>
-              ))}
-            </div>
-
-            <div className="mt-4 flex space-x-2">
-              <button
-                onClick={onUpgrade}
-                className="flex-1 px-3 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 text-sm"
-              >
-                Upgrade
-              </button>
-              <button
-                onClick={onRepair}
-                className="flex-1 px-3 py-2 bg-green-600 text-white rounded hover:bg-green-700 text-sm"
-              >
-                Repair
-              </button>
-            </div>
-          </div>
-        )}
-      </div>
-    </div>
-  );
+'use client';
+
+import { useState } from 'react';
+import { config } from '../config';
+
+interface ShipCardProps {
+  name: string;
+  type: string;
+  health: number;
+  cargo: number;
+  resources: { [key: string]: number };
+  onUpgrade?: () => void;
+  onRepair?: () => void;
+}
+
+export default function ShipCard({
+  name,
+  type,
+  health,
+  cargo,
+  resources,
+  onUpgrade,
+  onRepair,
+}: ShipCardProps) {
+  const [isExpanded, setIsExpanded] = useState(false);
+
+  return (
+    <div className="bg-gray-800 rounded-lg overflow-hidden shadow-lg hover:shadow-xl transition-shadow">
+      <div className="p-4">
+        <div className="flex justify-between items-start">
+          <div>
+            <h3 className="text-xl font-bold text-white">{name}</h3>
+            <p className="text-sm text-gray-400">{type}</p>
+          </div>
+          <button
+            onClick={() => setIsExpanded(!isExpanded)}
+            className="text-gray-400 hover:text-white"
+          >
+            {isExpanded ? '▼' : '▶'}
+          </button>
+        </div>
+
+        <div className="mt-4 space-y-2">
+          <div className="flex items-center">
+            <div className="w-24 h-2 bg-gray-700 rounded-full">
+              <div
+                className="h-full bg-blue-600 rounded-full"
+                style={{ width: `${health}%` }}
+              />
+            </div>
+            <span className="ml-2 text-sm text-gray-300">{health}%</span>
+          </div>
+
+          <div className="flex items-center">
+            <div className="w-24 h-2 bg-gray-700 rounded-full">
+              <div
+                className="h-full bg-green-600 rounded-full"
+                style={{ width: `${(cargo / 1000) * 100}%` }}
+              />
+            </div>
+            <span className="ml-2 text-sm text-gray-300">{cargo}/1000</span>
+          </div>
+        </div>
+
+        {isExpanded && (
+          <div className="mt-4 pt-4 border-t border-gray-700">
+            <h4 className="text-sm font-semibold text-gray-300 mb-2">Resources</h4>
+            <div className="grid grid-cols-2 gap-2">
+              {Object.entries(resources).map(([resource, amount]) => (
+                <div key={resource} className="flex justify-between items-center">
+                  <span className="text-sm text-gray-400">
+                    {config.game.resources.find(r => r.id === resource)?.name || resource}
+                  </span>
+                  <span className="text-sm text-white">{amount}</span>
+                </div>
+              ))}
+            </div>
+
+            <div className="mt-4 flex space-x-2">
+              <button
+                onClick={onUpgrade}
+                className="flex-1 px-3 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 text-sm"
+              >
+                Upgrade
+              </button>
+              <button
+                onClick={onRepair}
+                className="flex-1 px-3 py-2 bg-green-600 text-white rounded hover:bg-green-700 text-sm"
+              >
+                Repair
+              </button>
+            </div>
+          </div>
+        )}
+      </div>
+    </div>
+  );
 } 
\ No newline at end of file
@@ -1,92 +1,92 @@
-import { SorobanClient } from 'soroban-client';
-import { getPublicKey } from '@stellar/freighter-api';
-
-// Contract IDs (to be replaced with actual deployed contract IDs)
-const CONTRACTS = {
-  SPACE_GAME: 'space_game_contract_id',
-  STAR_SYSTEM: 'star_system_contract_id',
-  MISSIONS: 'missions_contract_id',
-  TRADING: 'trading_contract_id',
-};
-
-// Initialize Soroban client
-const sorobanClient = new SorobanClient('https://soroban-testnet.stellar.org');
-
-// Helper function to get the connected wallet address
-export async function getWalletAddress(): Promise<string> {
-  try {
-    return await getPublicKey();
-  } catch (error) {
-    console.error('Error getting wallet address:', error);
-    throw error;
-  }
-}
-
-// Game Contract Functions
-export async function initializePlayer(address: string) {
-  try {
-    // Implementation for initializing player
-    // This will call the initialize_player function in the space_game contract
-  } catch (error) {
-    console.error('Error initializing player:', error);
-    throw error;
-  }
-}
-
-export async function createShip(name: string, shipType: string) {
-  try {
-    // Implementation for creating a ship
-    // This will call the create_ship function in the space_game contract
-  } catch (error) {
-    console.error('Error creating ship:', error);
-    throw error;
-  }
-}
-
-// Star System Functions
-export async function discoverStar(starName: string) {
-  try {
-    // Implementation for discovering a star
-    // This will call the discover_star function in the star_system contract
-  } catch (error) {
-    console.error('Error discovering star:', error);
-    throw error;
-  }
-}
-
-// Mission Functions
-export async function checkMissionCompletion(missionId: string) {
-  try {
-    // Implementation for checking mission completion
-    // This will call the check_mission_completion function in the missions contract
-  } catch (error) {
-    console.error('Error checking mission:', error);
-    throw error;
-  }
-}
-
-// Trading Functions
-export async function createTradeOffer(
-  sellResource: string,
-  sellAmount: number,
-  buyResource: string,
-  buyAmount: number
-) {
-  try {
-    // Implementation for creating a trade offer
-    // This will call the create_offer function in the trading contract
-  } catch (error) {
-    console.error('Error creating trade offer:', error);
-    throw error;
-  }
-}
-
-export async function acceptTradeOffer(offerId: number) {
-  try {
-    // Implementation for accepting a trade offer
-    // This will call the accept_offer function in the trading contract
-  } catch (error) {
-    console.error('Error accepting trade offer:', error);
-    throw error;
-  }
+import { SorobanClient } from 'soroban-client';
+import { getPublicKey } from '@stellar/freighter-api';
+
+// Contract IDs (to be replaced with actual deployed contract IDs)
+const CONTRACTS = {
+  SPACE_GAME: 'space_game_contract_id',
+  STAR_SYSTEM: 'star_system_contract_id',
+  MISSIONS: 'missions_contract_id',
+  TRADING: 'trading_contract_id',
+};
+
+// Initialize Soroban client
+const sorobanClient = new SorobanClient('https://soroban-testnet.stellar.org');
+
+// Helper function to get the connected wallet address
+export async function getWalletAddress(): Promise<string> {
+  try {
+    return await getPublicKey();
+  } catch (error) {
+    console.error('Error getting wallet address:', error);
+    throw error;
+  }
+}
+
+// Game Contract Functions
+export async function initializePlayer(address: string) {
+  try {
+    // Implementation for initializing player
+    // This will call the initialize_player function in the space_game contract
+  } catch (error) {
+    console.error('Error initializing player:', error);
+    throw error;
+  }
+}
+
+export async function createShip(name: string, shipType: string) {
+  try {
+    // Implementation for creating a ship
+    // This will call the create_ship function in the space_game contract
+  } catch (error) {
+    console.error('Error creating ship:', error);
+    throw error;
+  }
+}
+
+// Star System Functions
+export async function discoverStar(starName: string) {
+  try {
+    // Implementation for discovering a star
+    // This will call the discover_star function in the star_system contract
+  } catch (error) {
+    console.error('Error discovering star:', error);
+    throw error;
+  }
+}
+
+// Mission Functions
+export async function checkMissionCompletion(missionId: string) {
+  try {
+    // Implementation for checking mission completion
+    // This will call the check_mission_completion function in the missions contract
+  } catch (error) {
+    console.error('Error checking mission:', error);
+    throw error;
+  }
+}
+
+// Trading Functions
+export async function createTradeOffer(
+  sellResource: string,
+  sellAmount: number,
+  buyResource: string,
+  buyAmount: number
+) {
+  try {
+    // Implementation for creating a trade offer
+    // This will call the create_offer function in the trading contract
+  } catch (error) {
+    console.error('Error creating trade offer:', error);
+    throw error;
+  }
+}
+
+export async function acceptTradeOffer(offerId: number) {
+  try {
+    // Implementation for accepting a trade offer
+    // This will call the accept_offer function in the trading contract
+  } catch (error) {
+    console.error('Error accepting trade offer:', error);
+    throw error;
+  }
 } 
\ No newline at end of file